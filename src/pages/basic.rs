@@ -1,27 +1,165 @@
 use serde::{Deserialize, Serialize};
-use web_sys::HtmlInputElement;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
 use yew::prelude::*;
 
 use super::html_utils::{
     INPUT_CLASS, INPUT_DIVIDER_CLASS, INPUT_FIELD_WRAPPER_CLASS, INPUT_SECTION_CLASS,
-    INPUT_SECTION_ROW_CLASS, LABEL_CLASS,
+    INPUT_SECTION_ROW_CLASS, LABEL_CLASS, SMALL_ADD_BUTTON_CLASS, SMALL_BUTTON_CLASS,
+    SUBSECTION_LABEL_CLASS,
 };
+use super::photo::{Photo, PhotoEditor, PhotoShape};
+use super::themes::Theme;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum LinkKind {
+    LinkedIn,
+    GitHub,
+    Website,
+    GoogleScholar,
+    X,
+    Mastodon,
+    Orcid,
+    Portfolio,
+    #[default]
+    Custom,
+}
+
+/// How a link's icon is drawn: a devicon class, or a text glyph for sites
+/// devicon doesn't cover.
+enum LinkIcon {
+    Class(&'static str),
+    Glyph(&'static str),
+}
+
+impl LinkKind {
+    pub fn all() -> [LinkKind; 9] {
+        [
+            LinkKind::LinkedIn,
+            LinkKind::GitHub,
+            LinkKind::Website,
+            LinkKind::GoogleScholar,
+            LinkKind::X,
+            LinkKind::Mastodon,
+            LinkKind::Orcid,
+            LinkKind::Portfolio,
+            LinkKind::Custom,
+        ]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LinkKind::LinkedIn => "LinkedIn",
+            LinkKind::GitHub => "Github",
+            LinkKind::Website => "Website",
+            LinkKind::GoogleScholar => "Google Scholar",
+            LinkKind::X => "X",
+            LinkKind::Mastodon => "Mastodon",
+            LinkKind::Orcid => "ORCID",
+            LinkKind::Portfolio => "Portfolio",
+            LinkKind::Custom => "Link",
+        }
+    }
+
+    fn icon(&self) -> LinkIcon {
+        match self {
+            LinkKind::LinkedIn => LinkIcon::Class("devicon-linkedin-plain"),
+            LinkKind::GitHub => LinkIcon::Class("devicon-github-original text-black"),
+            LinkKind::Website => LinkIcon::Glyph("🌐"),
+            LinkKind::GoogleScholar => LinkIcon::Glyph("🎓"),
+            LinkKind::X => LinkIcon::Glyph("𝕏"),
+            LinkKind::Mastodon => LinkIcon::Glyph("🐘"),
+            LinkKind::Orcid => LinkIcon::Glyph("iD"),
+            LinkKind::Portfolio => LinkIcon::Glyph("💼"),
+            LinkKind::Custom => LinkIcon::Glyph("🔗"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct ContactLink {
+    #[serde(default)]
+    pub kind: LinkKind,
+    /// Overrides the kind's default label when set.
+    #[serde(default)]
+    pub label: String,
+    pub url: String,
+}
+
+impl ContactLink {
+    pub fn new(kind: LinkKind, url: &str) -> Self {
+        ContactLink {
+            kind,
+            label: String::new(),
+            url: url.to_string(),
+        }
+    }
+
+    pub fn display_label(&self) -> &str {
+        match self.label.trim() {
+            "" => self.kind.label(),
+            label => label,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum LinkDisplay {
+    #[default]
+    Icon,
+    Text,
+    Url,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum HeaderLayout {
+    /// Everything centered, contact details on one row.
+    #[default]
+    Centered,
+    /// Name and headline on the left, contact details stacked on the right.
+    Split,
+    /// Everything left aligned.
+    Left,
+    /// The photo on the left, with the name and contact details beside it.
+    PhotoLeft,
+    /// Name and contact details stacked on the left, the photo on the right.
+    PhotoRight,
+}
 
 #[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
 pub struct Basic {
     pub name: String,
+    #[serde(default)]
+    pub pronouns: String,
+    /// A professional headline, e.g. "Head of Tools Engineering".
+    #[serde(default)]
+    pub headline: String,
+    /// City, or remote status.
+    #[serde(default)]
+    pub location: String,
+    /// Visa or work authorization line, e.g. "US Citizen".
+    #[serde(default)]
+    pub work_authorization: String,
     pub email: String,
     pub phone: String,
-    pub linkedin_url: Option<String>,
-    pub github_url: Option<String>,
+    /// Shown in order after the phone number and email.
+    #[serde(default)]
+    pub links: Vec<ContactLink>,
+    #[serde(default)]
+    pub link_display: LinkDisplay,
+    #[serde(default)]
+    pub header_layout: HeaderLayout,
+    #[serde(default)]
+    pub photo: Photo,
 }
 
 pub enum BasicField {
     Name(String),
+    Pronouns(String),
+    Headline(String),
+    Location(String),
+    WorkAuthorization(String),
     Email(String),
     Phone(String),
-    LinkedInUrl(String),
-    GithubUrl(String),
 }
 
 #[derive(Properties, PartialEq)]
@@ -39,6 +177,34 @@ pub fn basic_controller(props: &BasicControllerProps) -> Html {
         props,
     );
 
+    let pronouns_input = make_input(
+        "Pronouns".to_string(),
+        props.value.pronouns.clone(),
+        BasicField::Pronouns,
+        props,
+    );
+
+    let headline_input = make_input(
+        "Headline".to_string(),
+        props.value.headline.clone(),
+        BasicField::Headline,
+        props,
+    );
+
+    let location_input = make_input(
+        "Location".to_string(),
+        props.value.location.clone(),
+        BasicField::Location,
+        props,
+    );
+
+    let work_authorization_input = make_input(
+        "Work Authorization".to_string(),
+        props.value.work_authorization.clone(),
+        BasicField::WorkAuthorization,
+        props,
+    );
+
     let email_input = make_input(
         "Email".to_string(),
         props.value.email.clone(),
@@ -53,19 +219,91 @@ pub fn basic_controller(props: &BasicControllerProps) -> Html {
         props,
     );
 
-    let linkedin_url_input = make_input(
-        "LinkedIn URL".to_string(),
-        props.value.linkedin_url.clone().unwrap_or_default(),
-        BasicField::LinkedInUrl,
-        props,
-    );
+    let update_links = {
+        let basic = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |links: Vec<ContactLink>| {
+            on_change.emit(Basic {
+                links,
+                ..basic.clone()
+            });
+        })
+    };
+    let update_photo = {
+        let basic = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |photo: Photo| {
+            on_change.emit(Basic {
+                photo,
+                ..basic.clone()
+            });
+        })
+    };
+    let update_link_display = {
+        let basic = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            let link_display = match select.value().as_str() {
+                "Text" => LinkDisplay::Text,
+                "Url" => LinkDisplay::Url,
+                _ => LinkDisplay::Icon,
+            };
+            on_change.emit(Basic {
+                link_display,
+                ..basic.clone()
+            });
+        })
+    };
+    let display_options = [
+        (LinkDisplay::Icon, "Icon and label"),
+        (LinkDisplay::Text, "Label only"),
+        (LinkDisplay::Url, "Full URL"),
+    ]
+    .into_iter()
+    .map(|(display, label)| {
+        html! {
+            <option value={format!("{:?}", display)} selected={props.value.link_display == display}>
+                {label}
+            </option>
+        }
+    })
+    .collect::<Html>();
 
-    let github_url_input = make_input(
-        "Github URL".to_string(),
-        props.value.github_url.clone().unwrap_or_default(),
-        BasicField::GithubUrl,
-        props,
-    );
+    let update_header_layout = {
+        let basic = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            let header_layout = match select.value().as_str() {
+                "Split" => HeaderLayout::Split,
+                "Left" => HeaderLayout::Left,
+                "PhotoLeft" => HeaderLayout::PhotoLeft,
+                "PhotoRight" => HeaderLayout::PhotoRight,
+                _ => HeaderLayout::Centered,
+            };
+            on_change.emit(Basic {
+                header_layout,
+                ..basic.clone()
+            });
+        })
+    };
+    let layout_options = [
+        (HeaderLayout::Centered, "Centered"),
+        (HeaderLayout::Split, "Split"),
+        (HeaderLayout::Left, "Left aligned"),
+        (HeaderLayout::PhotoLeft, "Photo on the left"),
+        (HeaderLayout::PhotoRight, "Photo on the right"),
+    ]
+    .into_iter()
+    .map(|(layout, label)| {
+        html! {
+            <option value={format!("{:?}", layout)} selected={props.value.header_layout == layout}>
+                {label}
+            </option>
+        }
+    })
+    .collect::<Html>();
 
     html! {
         <>
@@ -73,11 +311,37 @@ pub fn basic_controller(props: &BasicControllerProps) -> Html {
             <h5 class={INPUT_SECTION_CLASS}> {"Basic Information"} </h5>
         </div>
         <div class={INPUT_DIVIDER_CLASS}></div>
-        {name_input}
+        <div class="flex">
+            <div class="w-2/3">
+                {name_input}
+            </div>
+            <div class="w-1/3">
+                {pronouns_input}
+            </div>
+        </div>
+        {headline_input}
+        {location_input}
+        {work_authorization_input}
         {email_input}
         {phone_input}
-        {linkedin_url_input}
-        {github_url_input}
+        <ContactLinksEditor value={props.value.links.clone()} on_change={update_links} />
+        <div class={INPUT_FIELD_WRAPPER_CLASS}>
+            <div class="relative">
+                <select class={INPUT_CLASS} onchange={update_link_display}>
+                    {display_options}
+                </select>
+                <label class={LABEL_CLASS}> {"Show Links As"} </label>
+            </div>
+        </div>
+        <div class={INPUT_FIELD_WRAPPER_CLASS}>
+            <div class="relative">
+                <select class={INPUT_CLASS} onchange={update_header_layout}>
+                    {layout_options}
+                </select>
+                <label class={LABEL_CLASS}> {"Header Layout"} </label>
+            </div>
+        </div>
+        <PhotoEditor value={props.value.photo.clone()} on_change={update_photo} />
         </>
     }
 }
@@ -93,10 +357,14 @@ where
         let mut next_basic = current_basic.clone();
         match cons(input.value()) {
             BasicField::Name(name) => next_basic.name = name,
+            BasicField::Pronouns(pronouns) => next_basic.pronouns = pronouns,
+            BasicField::Headline(headline) => next_basic.headline = headline,
+            BasicField::Location(location) => next_basic.location = location,
+            BasicField::WorkAuthorization(work_authorization) => {
+                next_basic.work_authorization = work_authorization
+            }
             BasicField::Email(email) => next_basic.email = email,
             BasicField::Phone(phone) => next_basic.phone = phone,
-            BasicField::LinkedInUrl(url) => next_basic.linkedin_url = Some(url),
-            BasicField::GithubUrl(url) => next_basic.github_url = Some(url),
         }
         on_change.emit(next_basic.clone());
     });
@@ -119,52 +387,321 @@ where
     }
 }
 
+#[derive(Properties, PartialEq)]
+struct ContactLinksEditorProps {
+    value: Vec<ContactLink>,
+    on_change: Callback<Vec<ContactLink>>,
+}
+
+#[function_component(ContactLinksEditor)]
+fn contact_links_editor(props: &ContactLinksEditorProps) -> Html {
+    let edit = |idx: usize, apply: fn(&mut Vec<ContactLink>, usize, String)| {
+        let links = props.value.clone();
+        let on_change = props.on_change.clone();
+        move |value: String| {
+            if idx < links.len() {
+                let mut next = links.clone();
+                apply(&mut next, idx, value);
+                on_change.emit(next);
+            }
+        }
+    };
+
+    let rows = props
+        .value
+        .iter()
+        .enumerate()
+        .map(|(idx, link)| {
+            let update_kind = {
+                let edit = edit(idx, |links, idx, value| {
+                    links[idx].kind = LinkKind::all()
+                        .into_iter()
+                        .find(|kind| format!("{:?}", kind) == value)
+                        .unwrap_or_default();
+                });
+                Callback::from(move |e: Event| {
+                    let select: HtmlSelectElement = e.target_unchecked_into();
+                    edit(select.value());
+                })
+            };
+            let update_label = {
+                let edit = edit(idx, |links, idx, value| links[idx].label = value);
+                Callback::from(move |e: InputEvent| {
+                    let input: HtmlInputElement = e.target_unchecked_into();
+                    edit(input.value());
+                })
+            };
+            let update_url = {
+                let edit = edit(idx, |links, idx, value| links[idx].url = value);
+                Callback::from(move |e: InputEvent| {
+                    let input: HtmlInputElement = e.target_unchecked_into();
+                    edit(input.value());
+                })
+            };
+            let move_up = {
+                let edit = edit(idx, |links, idx, _| {
+                    if idx > 0 {
+                        links.swap(idx, idx - 1);
+                    }
+                });
+                Callback::from(move |_| edit(String::new()))
+            };
+            let move_down = {
+                let edit = edit(idx, |links, idx, _| {
+                    if idx + 1 < links.len() {
+                        links.swap(idx, idx + 1);
+                    }
+                });
+                Callback::from(move |_| edit(String::new()))
+            };
+            let remove = {
+                let edit = edit(idx, |links, idx, _| {
+                    links.remove(idx);
+                });
+                Callback::from(move |_| edit(String::new()))
+            };
+            let kind_options = LinkKind::all()
+                .into_iter()
+                .map(|kind| {
+                    html! {
+                        <option value={format!("{:?}", kind)} selected={link.kind == kind}>
+                            {kind.label()}
+                        </option>
+                    }
+                })
+                .collect::<Html>();
+
+            html! {
+                <div class="border border-slate-800/80 rounded-lg p-0.5 my-0.5">
+                    <div class="grid grid-cols-[9rem_minmax(0,1fr)] gap-1">
+                        <div class="relative">
+                            <select class={INPUT_CLASS} onchange={update_kind}>
+                                {kind_options}
+                            </select>
+                            <label class={LABEL_CLASS}> {"Type"} </label>
+                        </div>
+                        <div class="relative">
+                            <input type="text"
+                                   class={INPUT_CLASS}
+                                   placeholder={link.kind.label()}
+                                   value={link.label.clone()}
+                                   oninput={update_label}
+                            />
+                            <label class={LABEL_CLASS}> {"Label"} </label>
+                        </div>
+                    </div>
+                    <div class="relative">
+                        <input type="text"
+                               class={INPUT_CLASS}
+                               value={link.url.clone()}
+                               oninput={update_url}
+                        />
+                        <label class={LABEL_CLASS}> {"URL"} </label>
+                    </div>
+                    <div class="mt-1 flex justify-end gap-x-1">
+                        <button class={SMALL_BUTTON_CLASS} onclick={move_up}> {"Up"} </button>
+                        <button class={SMALL_BUTTON_CLASS} onclick={move_down}> {"Down"} </button>
+                        <button class={SMALL_BUTTON_CLASS} onclick={remove}> {"Remove"} </button>
+                    </div>
+                </div>
+            }
+        })
+        .collect::<Html>();
+
+    let add_link = {
+        let links = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_| {
+            let mut next = links.clone();
+            next.push(ContactLink::default());
+            on_change.emit(next);
+        })
+    };
+
+    html! {
+        <div class={INPUT_FIELD_WRAPPER_CLASS}>
+            <div class="flex items-center justify-between px-1 py-1">
+                <div class={SUBSECTION_LABEL_CLASS}>{"Links"}</div>
+                <button class={SMALL_ADD_BUTTON_CLASS} onclick={add_link}>
+                    {"Add Link"}
+                </button>
+            </div>
+            {rows}
+        </div>
+    }
+}
+
+/// The visible URL for `LinkDisplay::Url`, without the scheme.
+fn display_url(url: &str) -> &str {
+    let url = url.trim();
+    let url = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
+    url.trim_end_matches('/')
+}
+
+fn view_link(link: &ContactLink, display: LinkDisplay) -> Html {
+    let content = match display {
+        LinkDisplay::Icon => {
+            let icon = match link.kind.icon() {
+                LinkIcon::Class(class) => html! { <i class={class}/> },
+                LinkIcon::Glyph(glyph) => html! { <span class="text-black"> {glyph} </span> },
+            };
+            html! { <> {icon} {" "}{link.display_label()} </> }
+        }
+        LinkDisplay::Text => html! { {link.display_label()} },
+        LinkDisplay::Url => html! { {display_url(&link.url)} },
+    };
+    html! {
+        <a class="text-blue-700" target="_blank" href={link.url.trim().to_string()}>
+            {content}
+        </a>
+    }
+}
+
 #[derive(Properties, PartialEq)]
 pub struct Props {
     pub basic: Basic,
+    pub theme: Theme,
+}
+
+fn view_photo(photo: &Photo) -> Html {
+    if photo.is_empty() {
+        return html! {};
+    }
+    let class = match photo.shape {
+        PhotoShape::Portrait => "w-[6.75rem] h-36 object-cover",
+        PhotoShape::Square => "w-32 h-32 object-cover",
+        PhotoShape::Round => "w-32 h-32 object-cover rounded-full",
+    };
+    html! { <img class={class} src={photo.data_url()} alt="Photo" /> }
+}
+
+/// Renders `text` with `class`, or nothing when it is blank.
+fn optional(text: &str, class: &'static str) -> Html {
+    match text.trim() {
+        "" => html! {},
+        text => html! { <div class={class}> {text.to_string()} </div> },
+    }
 }
 
 #[function_component(BasicViewer)]
 pub fn view_basic(props: &Props) -> Html {
-    let linkedin = match &props.basic.linkedin_url {
-        Some(url) => match url.is_empty() {
-            true => html! {},
-            false => html! {
-                <a class="text-blue-700" target="_blank" href={url.clone()}>
-                    <i class="devicon-linkedin-plain"/> {" LinkedIn"}
-                </a>
-            },
-        },
-        None => html! {},
+    let basic = &props.basic;
+    let links = basic
+        .links
+        .iter()
+        .filter(|link| !link.url.trim().is_empty())
+        .map(|link| view_link(link, basic.link_display))
+        .collect::<Html>();
+
+    let email = html! {
+        <a class="text-blue-700" href={"mailto:".to_string() + &basic.email.clone()}>
+            {basic.email.clone()}
+        </a>
     };
 
-    let github = match &props.basic.github_url {
-        Some(url) => match url.is_empty() {
-            true => html! {},
-            false => html! {
-                <a class="text-blue-700" target="_blank" href={url.clone()}>
-                    <i class="devicon-github-original text-black"/> {" Github"}
-                </a>
-            },
+    let pronouns = match basic.pronouns.trim() {
+        "" => html! {},
+        pronouns => html! {
+            <span class="text-xl text-gray-600"> {format!(" ({})", pronouns)} </span>
         },
-        None => html! {},
     };
-
-    let email = html! {
-        <a class="text-blue-700" href={"mailto:".to_string() + &props.basic.email.clone()}>
-            {props.basic.email.clone()}
-        </a>
+    let styles = props.theme.styles();
+    let name = html! {
+        <div class={styles.name}> {&basic.name} {pronouns} </div>
     };
+    let headline = optional(&basic.headline, styles.headline);
+    let work_authorization = optional(&basic.work_authorization, "text-base italic");
 
-    html! {
-        <>
-        <div class="text-4xl text-center"> {&props.basic.name} </div>
-        <div class="flex gap-x-8 text-center justify-center">
-            <div> {&props.basic.phone} </div>
-            {email}
-            {linkedin}
-            {github}
-        </div>
-        </>
+    match basic.header_layout {
+        HeaderLayout::Centered => html! {
+            <div class="text-center">
+                {name}
+                {headline}
+                <div class="flex gap-x-8 justify-center">
+                    {optional(&basic.location, "")}
+                    <div> {&basic.phone} </div>
+                    {email}
+                    {links}
+                </div>
+                {work_authorization}
+            </div>
+        },
+        HeaderLayout::Split => html! {
+            <div class="flex justify-between items-end gap-x-8">
+                <div>
+                    {name}
+                    {headline}
+                    {work_authorization}
+                </div>
+                <div class="flex flex-col items-end text-base">
+                    {optional(&basic.location, "")}
+                    <div> {&basic.phone} </div>
+                    {email}
+                    <div class="flex gap-x-4"> {links} </div>
+                </div>
+            </div>
+        },
+        HeaderLayout::Left => html! {
+            <div>
+                {name}
+                {headline}
+                <div class="flex gap-x-8">
+                    {optional(&basic.location, "")}
+                    <div> {&basic.phone} </div>
+                    {email}
+                    {links}
+                </div>
+                {work_authorization}
+            </div>
+        },
+        HeaderLayout::PhotoLeft => html! {
+            <div class="flex items-center gap-x-6">
+                {view_photo(&basic.photo)}
+                <div>
+                    {name}
+                    {headline}
+                    <div class="flex flex-wrap gap-x-6">
+                        {optional(&basic.location, "")}
+                        <div> {&basic.phone} </div>
+                        {email}
+                        {links}
+                    </div>
+                    {work_authorization}
+                </div>
+            </div>
+        },
+        HeaderLayout::PhotoRight => html! {
+            <div class="flex justify-between items-start gap-x-8">
+                <div>
+                    {name}
+                    {headline}
+                    <div class="flex flex-col text-base">
+                        {optional(&basic.location, "")}
+                        <div> {&basic.phone} </div>
+                        {email}
+                        <div class="flex gap-x-4"> {links} </div>
+                    </div>
+                    {work_authorization}
+                </div>
+                {view_photo(&basic.photo)}
+            </div>
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels_fall_back_to_the_link_kind() {
+        let mut link = ContactLink::new(LinkKind::GoogleScholar, "https://scholar.google.com/x");
+        assert_eq!(link.display_label(), "Google Scholar");
+        link.label = "Scholar".to_string();
+        assert_eq!(link.display_label(), "Scholar");
+        assert_eq!(display_url("https://allenchen.dev/"), "allenchen.dev");
     }
 }
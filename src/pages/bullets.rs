@@ -0,0 +1,267 @@
+use serde::{Deserialize, Serialize};
+use web_sys::{HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement};
+use yew::prelude::*;
+
+use super::html_utils::{
+    INPUT_CLASS, INPUT_FIELD_WRAPPER_CLASS, LABEL_CLASS, SMALL_ADD_BUTTON_CLASS,
+    SMALL_BUTTON_CLASS, SUBSECTION_LABEL_CLASS, TEXTAREA_CLASS,
+};
+use super::variants::{split_line_tags, TagsInput};
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct Bullet {
+    pub text: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub hidden: bool,
+    #[serde(default)]
+    pub priority: u8,
+    #[serde(default)]
+    pub notes: String,
+}
+
+impl From<&str> for Bullet {
+    /// Builds a bullet from a legacy description line, lifting a trailing
+    /// `{tag, tag}` block into `tags`.
+    fn from(line: &str) -> Self {
+        let (text, tags) = split_line_tags(line);
+        Bullet {
+            text: text.to_string(),
+            tags,
+            ..Bullet::default()
+        }
+    }
+}
+
+pub fn bullets_from_lines(description: &str) -> Vec<Bullet> {
+    description
+        .split('\n')
+        .filter(|line| !line.trim().is_empty())
+        .map(Bullet::from)
+        .collect()
+}
+
+/// Renders user-written Markdown.
+pub fn render_markdown(text: &str) -> Html {
+    Html::from_html_unchecked(markdown::to_html(text).into())
+}
+
+/// Renders the visible bullets as Markdown list lines.
+pub fn render_bullets(bullets: &[Bullet]) -> Html {
+    bullets
+        .iter()
+        .filter(|bullet| !bullet.hidden)
+        .map(|bullet| render_markdown(&(" - • ".to_string() + &bullet.text)))
+        .collect::<Html>()
+}
+
+#[derive(Properties, PartialEq)]
+pub struct BulletListEditorProps {
+    pub value: Vec<Bullet>,
+    pub on_change: Callback<Vec<Bullet>>,
+    #[prop_or(AttrValue::from("Bullets"))]
+    pub label: AttrValue,
+    /// Labels of other lists a bullet can be moved to.
+    #[prop_or_default]
+    pub move_targets: Vec<String>,
+    /// Emits `(bullet index, target index)` when a bullet is moved away.
+    #[prop_or_default]
+    pub on_move: Callback<(usize, usize)>,
+}
+
+#[function_component(BulletListEditor)]
+pub fn bullet_list_editor(props: &BulletListEditorProps) -> Html {
+    let update = |idx: usize, apply: fn(&mut Bullet, String)| {
+        let bullets = props.value.clone();
+        let on_change = props.on_change.clone();
+        move |value: String| {
+            let mut next = bullets.clone();
+            if let Some(bullet) = next.get_mut(idx) {
+                apply(bullet, value);
+                on_change.emit(next);
+            }
+        }
+    };
+
+    let rows = props
+        .value
+        .iter()
+        .enumerate()
+        .map(|(idx, bullet)| {
+            let update_text = {
+                let update = update(idx, |b, v| b.text = v);
+                Callback::from(move |e: InputEvent| {
+                    let input: HtmlTextAreaElement = e.target_unchecked_into();
+                    update(input.value());
+                })
+            };
+            let update_notes = {
+                let update = update(idx, |b, v| b.notes = v);
+                Callback::from(move |e: InputEvent| {
+                    let input: HtmlInputElement = e.target_unchecked_into();
+                    update(input.value());
+                })
+            };
+            let update_priority = {
+                let update = update(idx, |b, v| b.priority = v.parse().unwrap_or_default());
+                Callback::from(move |e: InputEvent| {
+                    let input: HtmlInputElement = e.target_unchecked_into();
+                    update(input.value());
+                })
+            };
+            let toggle_hidden = {
+                let bullets = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |_| {
+                    let mut next = bullets.clone();
+                    if let Some(bullet) = next.get_mut(idx) {
+                        bullet.hidden = !bullet.hidden;
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let update_tags = {
+                let bullets = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |tags: Vec<String>| {
+                    let mut next = bullets.clone();
+                    if let Some(bullet) = next.get_mut(idx) {
+                        bullet.tags = tags;
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let move_up = {
+                let bullets = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |_| {
+                    if idx > 0 && idx < bullets.len() {
+                        let mut next = bullets.clone();
+                        next.swap(idx, idx - 1);
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let move_down = {
+                let bullets = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |_| {
+                    if idx + 1 < bullets.len() {
+                        let mut next = bullets.clone();
+                        next.swap(idx, idx + 1);
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let remove_bullet = {
+                let bullets = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |_| {
+                    let mut next = bullets.clone();
+                    if idx < next.len() {
+                        next.remove(idx);
+                        on_change.emit(next);
+                    }
+                })
+            };
+
+            let move_select = if props.move_targets.is_empty() {
+                html! {}
+            } else {
+                let on_move = props.on_move.clone();
+                let onchange = Callback::from(move |e: Event| {
+                    let select: HtmlSelectElement = e.target_unchecked_into();
+                    if let Ok(target) = select.value().parse::<usize>() {
+                        on_move.emit((idx, target));
+                    }
+                });
+                let options = props
+                    .move_targets
+                    .iter()
+                    .enumerate()
+                    .map(|(target, label)| {
+                        html! { <option value={target.to_string()}> {label.clone()} </option> }
+                    })
+                    .collect::<Html>();
+                html! {
+                    <select class="rounded-md px-2 py-2 text-xs font-semibold text-white bg-slate-700" {onchange}>
+                        <option value="" selected=true> {"Move to..."} </option>
+                        {options}
+                    </select>
+                }
+            };
+
+            let row_class = if bullet.hidden {
+                "border border-slate-800/80 rounded-lg p-0.5 my-0.5 opacity-50"
+            } else {
+                "border border-slate-800/80 rounded-lg p-0.5 my-0.5"
+            };
+            html! {
+                <div class={row_class}>
+                    <div class="relative">
+                        <textarea
+                            class={TEXTAREA_CLASS}
+                            style="min-height: 64px"
+                            oninput={update_text}
+                            value={bullet.text.clone()}
+                        />
+                        <label class={LABEL_CLASS}> {format!("Bullet {}", idx + 1)} </label>
+                    </div>
+                    <TagsInput value={bullet.tags.clone()} on_change={update_tags} />
+                    <div class="grid grid-cols-[minmax(0,1fr)_6rem] gap-1">
+                        <div class="relative">
+                            <input type="text"
+                                   class={INPUT_CLASS}
+                                   value={bullet.notes.clone()}
+                                   oninput={update_notes}
+                            />
+                            <label class={LABEL_CLASS}> {"Notes"} </label>
+                        </div>
+                        <div class="relative">
+                            <input type="number"
+                                   min="0"
+                                   max="255"
+                                   class={INPUT_CLASS}
+                                   value={bullet.priority.to_string()}
+                                   oninput={update_priority}
+                            />
+                            <label class={LABEL_CLASS}> {"Priority"} </label>
+                        </div>
+                    </div>
+                    <div class="mt-1 flex justify-end gap-x-1">
+                        {move_select}
+                        <button class={SMALL_BUTTON_CLASS} onclick={toggle_hidden}>
+                            {if bullet.hidden { "Show" } else { "Hide" }}
+                        </button>
+                        <button class={SMALL_BUTTON_CLASS} onclick={move_up}> {"Up"} </button>
+                        <button class={SMALL_BUTTON_CLASS} onclick={move_down}> {"Down"} </button>
+                        <button class={SMALL_BUTTON_CLASS} onclick={remove_bullet}> {"Remove"} </button>
+                    </div>
+                </div>
+            }
+        })
+        .collect::<Html>();
+
+    let add_bullet = {
+        let bullets = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_| {
+            let mut next = bullets.clone();
+            next.push(Bullet::default());
+            on_change.emit(next);
+        })
+    };
+
+    html! {
+        <div class={INPUT_FIELD_WRAPPER_CLASS}>
+            <div class="flex items-center justify-between px-1 py-1">
+                <div class={SUBSECTION_LABEL_CLASS}>{props.label.clone()}</div>
+                <button class={SMALL_ADD_BUTTON_CLASS} onclick={add_bullet}>
+                    {"Add Bullet"}
+                </button>
+            </div>
+            {rows}
+        </div>
+    }
+}
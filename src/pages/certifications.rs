@@ -0,0 +1,375 @@
+use serde::{Deserialize, Serialize};
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use super::dates::{current_month, DateFormat, MonthInput, PartialDate};
+use super::html_utils::{
+    ADD_BUTTON_CLASS, INPUT_CLASS, INPUT_DIVIDER_CLASS, INPUT_FIELD_WRAPPER_CLASS,
+    INPUT_SECTION_CLASS, INPUT_SECTION_ROW_CLASS, LABEL_CLASS, REMOVE_BUTTON_CLASS,
+};
+use super::state::AppState;
+use super::themes::Theme;
+use super::variants::TagsInput;
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct Certification {
+    pub name: String,
+    #[serde(default)]
+    pub issuer: String,
+    #[serde(default)]
+    pub issued: Option<PartialDate>,
+    #[serde(default)]
+    pub expires: Option<PartialDate>,
+    #[serde(default)]
+    pub credential_id: String,
+    /// Where the credential can be verified.
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl Certification {
+    /// Certifications stay valid through their expiry month.
+    pub fn is_expired(&self, today: PartialDate) -> bool {
+        self.expires
+            .is_some_and(|expires| expires.months_since_epoch() < today.months_since_epoch())
+    }
+}
+
+/// Drops expired certifications when the state asks for it.
+pub fn hide_expired_certifications(state: &mut AppState, today: PartialDate) {
+    if state.hide_expired_certifications {
+        state
+            .certifications
+            .retain(|certification| !certification.is_expired(today));
+    }
+}
+
+pub enum CertificationField {
+    Name(String),
+    Issuer(String),
+    CredentialId(String),
+    Url(String),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct CertificationControllerProps {
+    pub value: Vec<Certification>,
+    pub on_change: Callback<Vec<Certification>>,
+    pub hide_expired: bool,
+    pub on_hide_expired_change: Callback<bool>,
+}
+
+#[function_component(CertificationController)]
+pub fn certification_controller(props: &CertificationControllerProps) -> Html {
+    let add_certification = {
+        let certifications = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_| {
+            let mut next = certifications.clone();
+            next.push(Certification::default());
+            on_change.emit(next);
+        })
+    };
+    let toggle_hide_expired = {
+        let hide_expired = props.hide_expired;
+        let on_change = props.on_hide_expired_change.clone();
+        Callback::from(move |_| on_change.emit(!hide_expired))
+    };
+
+    let today = current_month();
+    let inputs = props
+        .value
+        .iter()
+        .enumerate()
+        .map(|(idx, certification)| {
+            let remove_certification = {
+                let certifications = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |_| {
+                    let mut next = certifications.clone();
+                    if idx < next.len() {
+                        next.remove(idx);
+                        on_change.emit(next);
+                    }
+                })
+            };
+
+            let name_input = make_input(
+                props,
+                idx,
+                "Name".to_string(),
+                certification.name.clone(),
+                CertificationField::Name,
+            );
+            let issuer_input = make_input(
+                props,
+                idx,
+                "Issuer".to_string(),
+                certification.issuer.clone(),
+                CertificationField::Issuer,
+            );
+            let credential_id_input = make_input(
+                props,
+                idx,
+                "Credential ID".to_string(),
+                certification.credential_id.clone(),
+                CertificationField::CredentialId,
+            );
+            let url_input = make_input(
+                props,
+                idx,
+                "Verification URL".to_string(),
+                certification.url.clone(),
+                CertificationField::Url,
+            );
+
+            let update_date = |apply: fn(&mut Certification, Option<PartialDate>)| {
+                let certifications = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |date: Option<PartialDate>| {
+                    let mut next = certifications.clone();
+                    if let Some(certification) = next.get_mut(idx) {
+                        apply(certification, date);
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let update_tags = {
+                let certifications = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |tags: Vec<String>| {
+                    let mut next = certifications.clone();
+                    if let Some(certification) = next.get_mut(idx) {
+                        certification.tags = tags;
+                        on_change.emit(next);
+                    }
+                })
+            };
+
+            let expired = if certification.is_expired(today) {
+                html! {
+                    <div class="px-1 pt-1 text-xs text-rose-400">
+                        {if props.hide_expired {
+                            "Expired, hidden from the resume"
+                        } else {
+                            "Expired"
+                        }}
+                    </div>
+                }
+            } else {
+                html! {}
+            };
+
+            html! {
+                <>
+                    <div class="flex">
+                        <div class="w-1/2">
+                            {name_input}
+                        </div>
+                        <div class="w-1/2">
+                            {issuer_input}
+                        </div>
+                    </div>
+                    <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                        <div class="grid grid-cols-2 gap-1">
+                            <MonthInput
+                                label="Issued"
+                                value={certification.issued}
+                                on_change={update_date(|c, date| c.issued = date)}
+                            />
+                            <MonthInput
+                                label="Expires"
+                                value={certification.expires}
+                                on_change={update_date(|c, date| c.expires = date)}
+                            />
+                        </div>
+                        {expired}
+                    </div>
+                    {credential_id_input}
+                    {url_input}
+                    <TagsInput value={certification.tags.clone()} on_change={update_tags} />
+                    <button
+                        class={REMOVE_BUTTON_CLASS}
+                        onclick={remove_certification}
+                    >
+                        {"Remove"}
+                    </button>
+                </>
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <>
+            <div class={INPUT_SECTION_ROW_CLASS}>
+                <h5 class={INPUT_SECTION_CLASS}> {"Certifications"} </h5>
+                <button
+                    class={ADD_BUTTON_CLASS}
+                    onclick={add_certification}
+                >
+                    {"Add New"}
+                </button>
+            </div>
+            <div class={INPUT_DIVIDER_CLASS}></div>
+            <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                <label class="flex items-center gap-x-1 px-1 py-0.5 text-xs text-slate-300">
+                    <input type="checkbox"
+                           checked={props.hide_expired}
+                           onclick={toggle_hide_expired}
+                    />
+                    {"Hide expired certifications"}
+                </label>
+            </div>
+            {inputs}
+        </>
+    }
+}
+
+fn make_input<F>(
+    props: &CertificationControllerProps,
+    idx: usize,
+    name: String,
+    value: String,
+    cons: F,
+) -> Html
+where
+    F: Fn(String) -> CertificationField + 'static,
+{
+    let certifications = props.value.clone();
+    let on_change = props.on_change.clone();
+    let callback = Callback::from(move |e: InputEvent| {
+        let input: HtmlInputElement = e.target_unchecked_into();
+        let mut next = certifications.clone();
+        if let Some(certification) = next.get_mut(idx) {
+            match cons(input.value()) {
+                CertificationField::Name(name) => certification.name = name,
+                CertificationField::Issuer(issuer) => certification.issuer = issuer,
+                CertificationField::CredentialId(id) => certification.credential_id = id,
+                CertificationField::Url(url) => certification.url = url,
+            }
+            on_change.emit(next);
+        }
+    });
+    html! {
+    <div class={INPUT_FIELD_WRAPPER_CLASS}>
+        <div class="relative">
+            <input type="text"
+                   id={name.clone()}
+                   oninput={callback}
+                   class={INPUT_CLASS}
+                   value={value}
+            />
+            <label for={name.clone()}
+                   class={LABEL_CLASS}>
+                {name}
+            </label>
+        </div>
+    </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub certifications: Vec<Certification>,
+    pub heading: String,
+    pub theme: Theme,
+    pub date_format: DateFormat,
+}
+
+#[function_component(CertificationViewer)]
+pub fn view_certification(props: &Props) -> Html {
+    if props.certifications.is_empty() {
+        return html! {};
+    }
+    let style = props.date_format.style;
+    let certifications = props
+        .certifications
+        .iter()
+        .map(|certification| {
+            let name = if certification.url.trim().is_empty() {
+                html! { <span class="font-semibold"> {&certification.name} </span> }
+            } else {
+                html! {
+                    <a href={certification.url.clone()} class="font-semibold text-blue-600">
+                        {&certification.name}
+                    </a>
+                }
+            };
+            let issuer = match certification.issuer.trim() {
+                "" => html! {},
+                issuer => html! { <span> {", "}{issuer.to_string()} </span> },
+            };
+            let credential_id = match certification.credential_id.trim() {
+                "" => html! {},
+                id => html! {
+                    <span class="text-gray-600"> {format!(" (ID: {})", id)} </span>
+                },
+            };
+            let dates = [
+                certification
+                    .issued
+                    .map(|date| format!("Issued {}", date.format(style))),
+                certification
+                    .expires
+                    .map(|date| format!("Expires {}", date.format(style))),
+            ]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" · ");
+            html! {
+                <div class="flex justify-between">
+                    <div> {name} {issuer} {credential_id} </div>
+                    <div> {dates} </div>
+                </div>
+            }
+        })
+        .collect::<Html>();
+    html! {
+        <>
+            <h5 class={props.theme.styles().section_header}> {props.heading.clone()} </h5>
+            <div class="mx-2 my-1 flex flex-col -space-y-1">
+                {certifications}
+            </div>
+        </>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::dates::parse_date;
+    use crate::pages::state::default_seed_state;
+
+    #[test]
+    fn expired_certifications_are_hidden_on_request() {
+        let today = parse_date("2026/10").expect("valid date");
+        let certification = |name: &str, expires: &str| Certification {
+            name: name.to_string(),
+            expires: parse_date(expires),
+            ..Default::default()
+        };
+        let mut state = default_seed_state();
+        state.certifications = vec![
+            certification("CKA", "2026/09"),
+            certification("AWS SAA", "2026/10"),
+            certification("PMP", ""),
+        ];
+        assert!(state.certifications[0].is_expired(today));
+        assert!(!state.certifications[1].is_expired(today));
+
+        hide_expired_certifications(&mut state, today);
+        assert_eq!(state.certifications.len(), 3);
+
+        state.hide_expired_certifications = true;
+        hide_expired_certifications(&mut state, today);
+        let names = state
+            .certifications
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["AWS SAA", "PMP"]);
+    }
+}
@@ -0,0 +1,668 @@
+use serde::{Deserialize, Serialize};
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use super::html_utils::{
+    INPUT_CLASS, INPUT_DIVIDER_CLASS, INPUT_FIELD_WRAPPER_CLASS, INPUT_SECTION_CLASS,
+    INPUT_SECTION_ROW_CLASS, LABEL_CLASS,
+};
+
+static MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedDate")]
+pub struct PartialDate {
+    pub year: u16,
+    pub month: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub day: Option<u8>,
+}
+
+/// A `PartialDate` as stored, before its month and day are checked.
+#[derive(Deserialize)]
+struct UncheckedDate {
+    year: u16,
+    month: u8,
+    #[serde(default)]
+    day: Option<u8>,
+}
+
+impl TryFrom<UncheckedDate> for PartialDate {
+    type Error = String;
+
+    fn try_from(unchecked: UncheckedDate) -> Result<Self, Self::Error> {
+        let date = PartialDate {
+            year: unchecked.year,
+            month: unchecked.month,
+            day: unchecked.day,
+        };
+        match date_problem(&date) {
+            Some(problem) => Err(problem),
+            None => Ok(date),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum DateEnd {
+    Present,
+    On(PartialDate),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+pub struct DateRange {
+    #[serde(default)]
+    pub start: Option<PartialDate>,
+    #[serde(default)]
+    pub end: Option<DateEnd>,
+    /// The original text, kept only when it could not be parsed.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub text: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum DateStyle {
+    /// 2025/01
+    #[default]
+    Numeric,
+    /// Jan 2025
+    Short,
+    /// January 2025
+    Long,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+pub struct DateFormat {
+    #[serde(default)]
+    pub style: DateStyle,
+    #[serde(default)]
+    pub show_duration: bool,
+}
+
+impl PartialDate {
+    pub fn months_since_epoch(&self) -> i32 {
+        self.year as i32 * 12 + self.month as i32 - 1
+    }
+
+    pub fn format(&self, style: DateStyle) -> String {
+        let month_name = self
+            .month
+            .checked_sub(1)
+            .and_then(|idx| MONTH_NAMES.get(idx as usize))
+            .copied()
+            .unwrap_or_default();
+        let short_name = month_name.get(..3).unwrap_or(month_name);
+        match (style, self.day) {
+            (DateStyle::Numeric, Some(day)) => {
+                format!("{}/{:02}/{:02}", self.year, self.month, day)
+            }
+            (DateStyle::Numeric, None) => format!("{}/{:02}", self.year, self.month),
+            (DateStyle::Short, Some(day)) => format!("{} {}, {}", short_name, day, self.year),
+            (DateStyle::Short, None) => format!("{} {}", short_name, self.year),
+            (DateStyle::Long, Some(day)) => format!("{} {}, {}", month_name, day, self.year),
+            (DateStyle::Long, None) => format!("{} {}", month_name, self.year),
+        }
+    }
+
+    /// The `<input>` type that edits this date without losing its day.
+    fn input_type(&self) -> &'static str {
+        if self.day.is_some() {
+            "date"
+        } else {
+            "month"
+        }
+    }
+
+    /// The value for `input_type`: `YYYY-MM-DD`, or `YYYY-MM` without a day.
+    fn input_value(&self) -> String {
+        match self.day {
+            Some(day) => format!("{:04}-{:02}-{:02}", self.year, self.month, day),
+            None => format!("{:04}-{:02}", self.year, self.month),
+        }
+    }
+}
+
+/// The input type and value for an optional date; empty inputs pick months.
+fn date_input(date: Option<PartialDate>) -> (&'static str, String) {
+    match date {
+        Some(date) => (date.input_type(), date.input_value()),
+        None => ("month", String::new()),
+    }
+}
+
+impl DateEnd {
+    pub fn resolve(&self, today: PartialDate) -> PartialDate {
+        match self {
+            DateEnd::Present => today,
+            DateEnd::On(date) => *date,
+        }
+    }
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+pub fn month_from_name(name: &str) -> Option<u8> {
+    let name = name.trim_end_matches('.').to_ascii_lowercase();
+    if name.len() < 3 {
+        return None;
+    }
+    MONTH_NAMES
+        .iter()
+        .position(|month| month.to_ascii_lowercase().starts_with(&name))
+        .map(|idx| idx as u8 + 1)
+}
+
+pub fn is_present(text: &str) -> bool {
+    matches!(
+        text.trim().to_ascii_lowercase().as_str(),
+        "present" | "current" | "now" | "ongoing" | "today"
+    )
+}
+
+/// Parses `2025/01`, `2025-01-15`, `01/2025`, `Jan 2025` and `January 15, 2025`.
+pub fn parse_date(text: &str) -> Option<PartialDate> {
+    let text = text.trim();
+    let words = text
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>();
+    let (year, month, day) = match words.as_slice() {
+        [month, year] => (year.parse().ok()?, month_from_name(month)?, None),
+        [month, day, year] => (
+            year.parse().ok()?,
+            month_from_name(month)?,
+            Some(day.parse().ok()?),
+        ),
+        [numeric] => {
+            let parts = numeric
+                .split(['/', '-', '.'])
+                .map(|part| part.parse::<u16>().ok())
+                .collect::<Option<Vec<_>>>()?;
+            match parts.as_slice() {
+                [year, month] if *year >= 1000 => (*year, u8::try_from(*month).ok()?, None),
+                [month, year] if *year >= 1000 => (*year, u8::try_from(*month).ok()?, None),
+                [year, month, day] if *year >= 1000 => (
+                    *year,
+                    u8::try_from(*month).ok()?,
+                    Some(u8::try_from(*day).ok()?),
+                ),
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    let date = PartialDate { year, month, day };
+    date_problem(&date).is_none().then_some(date)
+}
+
+fn date_problem(date: &PartialDate) -> Option<String> {
+    if !(1..=12).contains(&date.month) {
+        return Some(format!("{} is not a valid month", date.month));
+    }
+    match date.day {
+        Some(day) if day == 0 || day > days_in_month(date.year, date.month) => Some(format!(
+            "{} has no day {}",
+            date.format(DateStyle::Long),
+            day
+        )),
+        _ => None,
+    }
+}
+
+fn split_range(text: &str) -> Option<(&str, &str)> {
+    [" - ", " – ", " — ", "–", "—", " to "]
+        .iter()
+        .find_map(|separator| text.split_once(separator))
+}
+
+impl DateRange {
+    /// Parses free text such as `2025/01 - Present`, falling back to keeping
+    /// the text as typed.
+    pub fn parse(text: &str) -> DateRange {
+        let text = text.trim();
+        if text.is_empty() {
+            return DateRange::default();
+        }
+        let parse_end = |end: &str| {
+            if is_present(end) {
+                Some(DateEnd::Present)
+            } else {
+                parse_date(end).map(DateEnd::On)
+            }
+        };
+        let parsed = match split_range(text) {
+            Some((start, end)) => parse_date(start)
+                .zip(parse_end(end))
+                .map(|(s, e)| (s, Some(e))),
+            None => parse_date(text).map(|start| (start, None)),
+        };
+        match parsed {
+            Some((start, end)) => DateRange {
+                start: Some(start),
+                end,
+                text: String::new(),
+            },
+            None => DateRange {
+                text: text.to_string(),
+                ..DateRange::default()
+            },
+        }
+    }
+
+    /// Builds a range from separate start and end strings.
+    pub fn from_parts(start: &str, end: &str) -> DateRange {
+        match (start.trim(), end.trim()) {
+            ("", "") => DateRange::default(),
+            (start, "") => DateRange::parse(start),
+            ("", end) => match DateRange::parse(end) {
+                DateRange {
+                    start: Some(date),
+                    end: None,
+                    ..
+                } => DateRange {
+                    end: Some(DateEnd::On(date)),
+                    ..DateRange::default()
+                },
+                unparsed => unparsed,
+            },
+            (start, end) => DateRange::parse(&format!("{} - {}", start, end)),
+        }
+    }
+
+    pub fn validate(&self) -> Option<String> {
+        if !self.text.is_empty() {
+            return Some(format!("\"{}\" is not a recognized date range", self.text));
+        }
+        let dates = self
+            .start
+            .iter()
+            .chain(self.end.iter().filter_map(|end| match end {
+                DateEnd::On(date) => Some(date),
+                DateEnd::Present => None,
+            }));
+        if let Some(problem) = dates.filter_map(date_problem).next() {
+            return Some(problem);
+        }
+        match (self.start, self.end) {
+            (Some(start), Some(DateEnd::On(end))) if end < start => {
+                Some("End date is before start date".to_string())
+            }
+            _ => None,
+        }
+    }
+
+    /// Whole months between start and end, or `None` when undated.
+    pub fn duration_months(&self, today: PartialDate) -> Option<i32> {
+        let start = self.start?;
+        let end = self.end?.resolve(today);
+        Some((end.months_since_epoch() - start.months_since_epoch()).max(0))
+    }
+
+    pub fn format(&self, format: &DateFormat, today: PartialDate) -> String {
+        if !self.text.is_empty() {
+            return self.text.clone();
+        }
+        let separator = match format.style {
+            DateStyle::Numeric => " - ",
+            DateStyle::Short | DateStyle::Long => " – ",
+        };
+        let range = match (self.start, self.end) {
+            (None, None) => return String::new(),
+            (None, Some(end)) => match end {
+                DateEnd::Present => "Present".to_string(),
+                DateEnd::On(end) => end.format(format.style),
+            },
+            (Some(start), None) => start.format(format.style),
+            (Some(start), Some(DateEnd::Present)) => {
+                format!("{}{}Present", start.format(format.style), separator)
+            }
+            (Some(start), Some(DateEnd::On(end))) => format!(
+                "{}{}{}",
+                start.format(format.style),
+                separator,
+                end.format(format.style)
+            ),
+        };
+        match self.duration_months(today) {
+            Some(months) if format.show_duration => {
+                format!("{} · {}", range, format_duration(months))
+            }
+            _ => range,
+        }
+    }
+}
+
+pub fn format_duration(months: i32) -> String {
+    let plural = |n: i32, unit: &str| match n {
+        1 => format!("1 {}", unit),
+        n => format!("{} {}s", n, unit),
+    };
+    match (months / 12, months % 12) {
+        (0, 0) => "less than 1 mo".to_string(),
+        (0, m) => plural(m, "mo"),
+        (y, 0) => plural(y, "yr"),
+        (y, m) => format!("{} {}", plural(y, "yr"), plural(m, "mo")),
+    }
+}
+
+/// The current month, used to resolve "Present".
+pub fn current_month() -> PartialDate {
+    let now = js_sys::Date::new_0();
+    PartialDate {
+        year: now.get_full_year() as u16,
+        month: now.get_month() as u8 + 1,
+        day: None,
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct DateRangeInputProps {
+    pub value: DateRange,
+    pub on_change: Callback<DateRange>,
+    #[prop_or(AttrValue::from("Dates"))]
+    pub label: AttrValue,
+}
+
+#[function_component(DateRangeInput)]
+pub fn date_range_input(props: &DateRangeInputProps) -> Html {
+    let update_start = {
+        let range = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            on_change.emit(DateRange {
+                start: parse_date(&input.value()),
+                text: String::new(),
+                ..range.clone()
+            });
+        })
+    };
+    let update_end = {
+        let range = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            on_change.emit(DateRange {
+                end: parse_date(&input.value()).map(DateEnd::On),
+                text: String::new(),
+                ..range.clone()
+            });
+        })
+    };
+    let toggle_present = {
+        let range = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_| {
+            let end = match range.end {
+                Some(DateEnd::Present) => None,
+                _ => Some(DateEnd::Present),
+            };
+            on_change.emit(DateRange {
+                end,
+                text: String::new(),
+                ..range.clone()
+            });
+        })
+    };
+
+    let is_present = props.value.end == Some(DateEnd::Present);
+    let (start_type, start_value) = date_input(props.value.start);
+    let (end_type, end_value) = date_input(match props.value.end {
+        Some(DateEnd::On(date)) => Some(date),
+        _ => None,
+    });
+    let error = match props.value.validate() {
+        Some(problem) => html! { <div class="px-1 pt-1 text-xs text-rose-400"> {problem} </div> },
+        None => html! {},
+    };
+
+    html! {
+        <div class={INPUT_FIELD_WRAPPER_CLASS}>
+            <div class="grid grid-cols-[minmax(0,1fr)_minmax(0,1fr)_auto] gap-1 items-center">
+                <div class="relative">
+                    <input type={start_type}
+                           class={INPUT_CLASS}
+                           value={start_value}
+                           onchange={update_start}
+                    />
+                    <label class={LABEL_CLASS}> {format!("{} Start", props.label)} </label>
+                </div>
+                <div class="relative">
+                    <input type={end_type}
+                           class={INPUT_CLASS}
+                           value={end_value}
+                           disabled={is_present}
+                           onchange={update_end}
+                    />
+                    <label class={LABEL_CLASS}> {format!("{} End", props.label)} </label>
+                </div>
+                <label class="flex items-center gap-x-1 px-1 text-xs text-slate-300">
+                    <input type="checkbox" checked={is_present} onclick={toggle_present} />
+                    {"Present"}
+                </label>
+            </div>
+            {error}
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct MonthInputProps {
+    pub value: Option<PartialDate>,
+    pub on_change: Callback<Option<PartialDate>>,
+    pub label: AttrValue,
+}
+
+/// A single optional month, or day when one is set, for dates that aren't
+/// part of a range.
+#[function_component(MonthInput)]
+pub fn month_input(props: &MonthInputProps) -> Html {
+    let onchange = {
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            on_change.emit(parse_date(&input.value()));
+        })
+    };
+    let (input_type, value) = date_input(props.value);
+    html! {
+        <div class="relative">
+            <input type={input_type} class={INPUT_CLASS} {value} {onchange} />
+            <label class={LABEL_CLASS}> {props.label.clone()} </label>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct DateFormatControllerProps {
+    pub value: DateFormat,
+    pub on_change: Callback<DateFormat>,
+}
+
+#[function_component(DateFormatController)]
+pub fn date_format_controller(props: &DateFormatControllerProps) -> Html {
+    let update_style = {
+        let format = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            let style = match select.value().as_str() {
+                "Short" => DateStyle::Short,
+                "Long" => DateStyle::Long,
+                _ => DateStyle::Numeric,
+            };
+            on_change.emit(DateFormat {
+                style,
+                ..format.clone()
+            });
+        })
+    };
+    let toggle_duration = {
+        let format = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_| {
+            on_change.emit(DateFormat {
+                show_duration: !format.show_duration,
+                ..format.clone()
+            });
+        })
+    };
+    let sample = PartialDate {
+        year: 2025,
+        month: 1,
+        day: None,
+    };
+    let options = [DateStyle::Numeric, DateStyle::Short, DateStyle::Long]
+        .into_iter()
+        .map(|style| {
+            html! {
+                <option value={format!("{:?}", style)} selected={props.value.style == style}>
+                    {sample.format(style)}
+                </option>
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <>
+            <div class={INPUT_SECTION_ROW_CLASS}>
+                <h5 class={INPUT_SECTION_CLASS}> {"Dates"} </h5>
+            </div>
+            <div class={INPUT_DIVIDER_CLASS}></div>
+            <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                <div class="grid grid-cols-[minmax(0,1fr)_auto] gap-1 items-center">
+                    <div class="relative">
+                        <select class={INPUT_CLASS} onchange={update_style}>
+                            {options}
+                        </select>
+                        <label class={LABEL_CLASS}> {"Date Style"} </label>
+                    </div>
+                    <label class="flex items-center gap-x-1 px-1 text-xs text-slate-300">
+                        <input type="checkbox" checked={props.value.show_duration} onclick={toggle_duration} />
+                        {"Show Durations"}
+                    </label>
+                </div>
+            </div>
+        </>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ym(year: u16, month: u8) -> PartialDate {
+        PartialDate {
+            year,
+            month,
+            day: None,
+        }
+    }
+
+    #[test]
+    fn parses_existing_date_strings() {
+        assert_eq!(parse_date("2021/08"), Some(ym(2021, 8)));
+        assert_eq!(parse_date("08/2021"), Some(ym(2021, 8)));
+        assert_eq!(parse_date("Aug 2021"), Some(ym(2021, 8)));
+        assert_eq!(
+            parse_date("January 15, 2025"),
+            Some(PartialDate {
+                year: 2025,
+                month: 1,
+                day: Some(15),
+            })
+        );
+        assert_eq!(parse_date("2021/13"), None);
+        assert_eq!(parse_date("2023/02/29"), None);
+
+        let range = DateRange::parse("2025/01 - Present");
+        assert_eq!(range.start, Some(ym(2025, 1)));
+        assert_eq!(range.end, Some(DateEnd::Present));
+
+        let range = DateRange::parse("Spring semester");
+        assert_eq!(range.text, "Spring semester");
+        assert!(range.validate().is_some());
+    }
+
+    #[test]
+    fn validate_rejects_end_before_start() {
+        let range = DateRange::from_parts("2022/05", "2021/08");
+        assert_eq!(
+            range.validate(),
+            Some("End date is before start date".to_string())
+        );
+        assert_eq!(DateRange::from_parts("2021/08", "2022/05").validate(), None);
+    }
+
+    #[test]
+    fn formats_ranges_with_duration() {
+        let format = DateFormat {
+            style: DateStyle::Short,
+            show_duration: true,
+        };
+        let range = DateRange::parse("2025/01 - Present");
+        assert_eq!(
+            range.format(&format, ym(2026, 10)),
+            "Jan 2025 – Present · 1 yr 9 mos"
+        );
+        assert_eq!(
+            range.format(&DateFormat::default(), ym(2026, 10)),
+            "2025/01 - Present"
+        );
+    }
+
+    #[test]
+    fn invalid_months_do_not_panic() {
+        for month in [0, 13] {
+            for style in [DateStyle::Numeric, DateStyle::Short, DateStyle::Long] {
+                ym(2021, month).format(style);
+                PartialDate {
+                    day: Some(1),
+                    ..ym(2021, month)
+                }
+                .format(style);
+            }
+        }
+        assert!(serde_json::from_str::<PartialDate>(r#"{"year":2021,"month":0}"#).is_err());
+        assert!(
+            serde_json::from_str::<PartialDate>(r#"{"year":2021,"month":2,"day":30}"#).is_err()
+        );
+        assert_eq!(
+            serde_json::from_str::<PartialDate>(r#"{"year":2021,"month":8}"#).ok(),
+            Some(ym(2021, 8))
+        );
+    }
+
+    #[test]
+    fn date_inputs_keep_the_day() {
+        let day_precise = parse_date("2025-01-15");
+        let (input_type, value) = date_input(day_precise);
+        assert_eq!((input_type, value.as_str()), ("date", "2025-01-15"));
+        assert_eq!(parse_date(&value), day_precise);
+
+        let (input_type, value) = date_input(Some(ym(2025, 1)));
+        assert_eq!((input_type, value.as_str()), ("month", "2025-01"));
+    }
+}
@@ -2,29 +2,47 @@ use serde::{Deserialize, Serialize};
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
+use super::bullets::{render_bullets, Bullet, BulletListEditor};
+use super::dates::{current_month, DateFormat, DateRange, DateRangeInput};
 use super::html_utils::{
     ADD_BUTTON_CLASS, INPUT_CLASS, INPUT_DIVIDER_CLASS, INPUT_FIELD_WRAPPER_CLASS,
     INPUT_SECTION_CLASS, INPUT_SECTION_ROW_CLASS, LABEL_CLASS, REMOVE_BUTTON_CLASS,
-    SECTION_HEADER_CLASS,
 };
+use super::themes::Theme;
+use super::timeline::EntryRef;
+use super::variants::TagsInput;
 
 #[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
 pub struct Education {
     pub school: String,
     pub degree: String,
     pub major: String,
-    pub start_date: String,
-    pub end_date: String,
+    #[serde(default)]
+    pub dates: DateRange,
+    #[serde(default)]
     pub gpa: String,
+    #[serde(default)]
+    pub honors: String,
+    #[serde(default)]
+    pub minor: String,
+    #[serde(default)]
+    pub thesis: String,
+    #[serde(default)]
+    pub coursework: Vec<String>,
+    #[serde(default)]
+    pub bullets: Vec<Bullet>,
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 pub enum EducationField {
     School(String),
     Degree(String),
     Major(String),
-    StartDate(String),
-    EndDate(String),
     Gpa(String),
+    Honors(String),
+    Minor(String),
+    Thesis(String),
 }
 
 #[derive(Properties, PartialEq)]
@@ -83,48 +101,114 @@ pub fn education_controller(props: &EducationControllerProps) -> Html {
                 education.major.clone(),
                 EducationField::Major,
             );
-            let start_date_input = make_input(
+            let update_dates = {
+                let educations = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |dates: DateRange| {
+                    let mut next = educations.clone();
+                    if let Some(education) = next.get_mut(idx) {
+                        education.dates = dates;
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let gpa_input = make_input(
+                props,
+                idx,
+                "GPA".to_string(),
+                education.gpa.clone(),
+                EducationField::Gpa,
+            );
+            let minor_input = make_input(
                 props,
                 idx,
-                "Start".to_string(),
-                education.start_date.clone(),
-                EducationField::StartDate,
+                "Minor".to_string(),
+                education.minor.clone(),
+                EducationField::Minor,
             );
-            let end_date_input = make_input(
+            let honors_input = make_input(
                 props,
                 idx,
-                "End".to_string(),
-                education.end_date.clone(),
-                EducationField::EndDate,
+                "Honors".to_string(),
+                education.honors.clone(),
+                EducationField::Honors,
             );
-            let gpa_input = make_input(
+            let thesis_input = make_input(
                 props,
                 idx,
-                "GPA".to_string(),
-                education.gpa.clone(),
-                EducationField::Gpa,
+                "Thesis".to_string(),
+                education.thesis.clone(),
+                EducationField::Thesis,
             );
+            let update_coursework = {
+                let educations = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |coursework: Vec<String>| {
+                    let mut next = educations.clone();
+                    if let Some(education) = next.get_mut(idx) {
+                        education.coursework = coursework;
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let update_bullets = {
+                let educations = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |bullets: Vec<Bullet>| {
+                    let mut next = educations.clone();
+                    if let Some(education) = next.get_mut(idx) {
+                        education.bullets = bullets;
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let update_tags = {
+                let educations = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |tags: Vec<String>| {
+                    let mut next = educations.clone();
+                    if let Some(education) = next.get_mut(idx) {
+                        education.tags = tags;
+                        on_change.emit(next);
+                    }
+                })
+            };
             html! {
-                <>
+                <div id={EntryRef::Education(idx).anchor_id()} tabindex="-1">
                     {school_input}
                     {major_input}
                     <div class="flex">
                         <div class="w-1/2">
                             {degree_input}
+                        </div>
+                        <div class="w-1/2">
                             {gpa_input}
                         </div>
+                    </div>
+                    <div class="flex">
                         <div class="w-1/2">
-                            {start_date_input}
-                            {end_date_input}
+                            {minor_input}
+                        </div>
+                        <div class="w-1/2">
+                            {honors_input}
                         </div>
                     </div>
+                    <DateRangeInput value={education.dates.clone()} on_change={update_dates} />
+                    {thesis_input}
+                    <TagsInput
+                        label="Relevant Coursework"
+                        value={education.coursework.clone()}
+                        on_change={update_coursework}
+                    />
+                    <BulletListEditor value={education.bullets.clone()} on_change={update_bullets} />
+                    <TagsInput value={education.tags.clone()} on_change={update_tags} />
                     <button
                         class={REMOVE_BUTTON_CLASS}
                         onclick={remove_education}
                     >
                         {"Remove"}
                     </button>
-                </>
+                </div>
             }
         })
         .collect::<Html>();
@@ -165,9 +249,10 @@ where
                 EducationField::School(school) => education.school = school,
                 EducationField::Degree(degree) => education.degree = degree,
                 EducationField::Major(major) => education.major = major,
-                EducationField::StartDate(start_date) => education.start_date = start_date,
-                EducationField::EndDate(end_date) => education.end_date = end_date,
                 EducationField::Gpa(gpa) => education.gpa = gpa,
+                EducationField::Honors(honors) => education.honors = honors,
+                EducationField::Minor(minor) => education.minor = minor,
+                EducationField::Thesis(thesis) => education.thesis = thesis,
             }
             on_change.emit(next);
         }
@@ -193,6 +278,29 @@ where
 #[derive(Properties, PartialEq)]
 pub struct Props {
     pub educations: Vec<Education>,
+    pub heading: String,
+    pub theme: Theme,
+    pub date_format: DateFormat,
+}
+
+/// Splits educations into runs of consecutive degrees from the same school.
+fn group_by_school(educations: &[Education]) -> Vec<&[Education]> {
+    educations
+        .chunk_by(|a, b| a.school.trim().eq_ignore_ascii_case(b.school.trim()))
+        .collect()
+}
+
+/// "Degree in Major, Minor in X", leaving out whatever is empty.
+fn degree_line(education: &Education) -> String {
+    let mut line = match (education.degree.trim(), education.major.trim()) {
+        ("", major) => major.to_string(),
+        (degree, "") => degree.to_string(),
+        (degree, major) => format!("{} in {}", degree, major),
+    };
+    if !education.minor.trim().is_empty() {
+        line.push_str(&format!(", Minor in {}", education.minor.trim()));
+    }
+    line
 }
 
 #[function_component(EducationViewer)]
@@ -200,33 +308,117 @@ pub fn view_education(props: &Props) -> Html {
     if props.educations.is_empty() {
         return html! {};
     }
-    let educations = props
-        .educations
-        .iter()
-        .map(|education| {
-            html! {
-                <div class="flex justify-between">
-                    <div class="flex gap-x-1">
-                        <span class="font-semibold"> {&education.school.clone()} {", "} </span>
-                        <span class=""> {&education.degree} </span>
-                        <span class=""> {&education.major} {", "} </span>
-                        <span class=""> {"GPA: "}{&education.gpa} </span>
-                    </div>
-                    <div class="flex gap-x-4">
-                        <span class="">
-                            {education.start_date.clone() + " - " + &education.end_date.clone()}
-                        </span>
-                    </div>
-                </div>
-            }
+    let today = current_month();
+    let educations = group_by_school(&props.educations)
+        .into_iter()
+        .map(|group| {
+            let degrees = group
+                .iter()
+                .enumerate()
+                .map(|(idx, education)| {
+                    let school_class = if idx == 0 {
+                        "font-semibold"
+                    } else {
+                        "invisible font-semibold"
+                    };
+                    let gpa = if education.gpa.trim().is_empty() {
+                        html! {}
+                    } else {
+                        html! { <span> {", GPA: "}{education.gpa.trim()} </span> }
+                    };
+                    let honors = if education.honors.trim().is_empty() {
+                        html! {}
+                    } else {
+                        html! { <span class="italic"> {", "}{education.honors.trim()} </span> }
+                    };
+                    let thesis = if education.thesis.trim().is_empty() {
+                        html! {}
+                    } else {
+                        html! {
+                            <div class="ml-4">
+                                <span class="font-semibold"> {"Thesis: "} </span>
+                                <span class="italic"> {education.thesis.trim()} </span>
+                            </div>
+                        }
+                    };
+                    let coursework = if education.coursework.is_empty() {
+                        html! {}
+                    } else {
+                        html! {
+                            <div class="ml-4">
+                                <span class="font-semibold"> {"Relevant Coursework: "} </span>
+                                <span> {education.coursework.join(", ")} </span>
+                            </div>
+                        }
+                    };
+                    html! {
+                        <>
+                            <div class="flex justify-between">
+                                <div class="flex gap-x-1">
+                                    <span class={school_class}> {&education.school} {", "} </span>
+                                    <span> {degree_line(education)} {gpa} {honors} </span>
+                                </div>
+                                <div class="flex gap-x-4">
+                                    <span class="">
+                                        {education.dates.format(&props.date_format, today)}
+                                    </span>
+                                </div>
+                            </div>
+                            {thesis}
+                            {coursework}
+                            {render_bullets(&education.bullets)}
+                        </>
+                    }
+                })
+                .collect::<Html>();
+            html! { <div> {degrees} </div> }
         })
         .collect::<Html>();
     html! {
         <>
-            <h5 class={SECTION_HEADER_CLASS}> {"Education"} </h5>
+            <h5 class={props.theme.styles().section_header}> {props.heading.clone()} </h5>
             <div class="mx-2 my-1 flex flex-col -space-y-1">
                 {educations}
             </div>
         </>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn education(school: &str, degree: &str) -> Education {
+        Education {
+            school: school.to_string(),
+            degree: degree.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn groups_consecutive_degrees_by_school() {
+        let educations = [
+            education("Georgia Tech", "M.S."),
+            education("georgia tech ", "B.S."),
+            education("Emory", "B.A."),
+        ];
+        let sizes = group_by_school(&educations)
+            .iter()
+            .map(|group| group.len())
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, [2, 1]);
+    }
+
+    #[test]
+    fn degree_line_skips_empty_parts() {
+        let mut education = education("Georgia Tech", "B.S.");
+        assert_eq!(degree_line(&education), "B.S.");
+        education.major = "Computer Engineering".to_string();
+        education.minor = "Mathematics".to_string();
+        assert_eq!(
+            degree_line(&education),
+            "B.S. in Computer Engineering, Minor in Mathematics"
+        );
+    }
+}
@@ -1,19 +1,28 @@
 use serde::{Deserialize, Serialize};
-use web_sys::{HtmlInputElement, HtmlTextAreaElement};
+use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
+use super::bullets::{render_bullets, Bullet, BulletListEditor};
+use super::dates::{current_month, DateFormat, DateRange, DateRangeInput};
 use super::html_utils::{
     ADD_BUTTON_CLASS, INPUT_CLASS, INPUT_DIVIDER_CLASS, INPUT_FIELD_WRAPPER_CLASS,
     INPUT_SECTION_CLASS, INPUT_SECTION_ROW_CLASS, LABEL_CLASS, REMOVE_BUTTON_CLASS,
-    SECTION_HEADER_CLASS, TEXTAREA_CLASS,
+    SMALL_ADD_BUTTON_CLASS, SMALL_BUTTON_CLASS, SUBSECTION_LABEL_CLASS,
 };
+use super::tech_stack::utilized_line;
+use super::themes::Theme;
+use super::timeline::EntryRef;
+use super::variants::TagsInput;
 
 #[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
 pub struct RolePeriod {
     pub title: String,
-    pub dates: String,
+    #[serde(default)]
+    pub dates: DateRange,
     #[serde(default)]
     pub location: String,
+    #[serde(default)]
+    pub bullets: Vec<Bullet>,
 }
 
 #[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
@@ -21,13 +30,13 @@ pub struct Experience {
     pub employer: String,
     pub team: Option<String>,
     #[serde(default)]
-    pub title: String,
-    #[serde(default)]
-    pub dates: String,
-    pub location: String,
-    pub description: String,
+    pub bullets: Vec<Bullet>,
     #[serde(default)]
     pub roles: Vec<RolePeriod>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub tech_stack: Vec<String>,
 }
 
 pub enum ExperienceField {
@@ -39,6 +48,14 @@ pub enum ExperienceField {
 pub struct ExperienceControllerProps {
     pub value: Vec<Experience>,
     pub on_change: Callback<Vec<Experience>>,
+    #[prop_or(AttrValue::from("Experiences"))]
+    pub title: AttrValue,
+    #[prop_or(AttrValue::from("Employer"))]
+    pub employer_label: AttrValue,
+    /// Employment entries have a tech stack and are the targets of the
+    /// timeline panel's links. Volunteering reuses the editor without either.
+    #[prop_or(true)]
+    pub employment: bool,
 }
 
 #[function_component(ExperienceController)]
@@ -76,7 +93,7 @@ pub fn experience_controller(props: &ExperienceControllerProps) -> Html {
             let employer_input = make_input(
                 props,
                 idx,
-                "Employer".to_string(),
+                props.employer_label.to_string(),
                 experience.employer.clone(),
                 ExperienceField::Employer,
             );
@@ -87,7 +104,42 @@ pub fn experience_controller(props: &ExperienceControllerProps) -> Html {
                 experience.team.clone().unwrap_or_default(),
                 ExperienceField::Team,
             );
-            let roles = roles_for_display(experience);
+            let update_tags = {
+                let experiences = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |tags: Vec<String>| {
+                    let mut next = experiences.clone();
+                    if let Some(experience) = next.get_mut(idx) {
+                        experience.tags = tags;
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let update_tech_stack = {
+                let experiences = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |tech_stack: Vec<String>| {
+                    let mut next = experiences.clone();
+                    if let Some(experience) = next.get_mut(idx) {
+                        experience.tech_stack = tech_stack;
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let roles = &experience.roles;
+            let owner_labels = bullet_owner_labels(roles);
+            let move_bullet_from = |owner: usize| {
+                let experiences = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |(bullet_idx, target): (usize, usize)| {
+                    let mut next = experiences.clone();
+                    if let Some(experience) = next.get_mut(idx) {
+                        let to = move_target_owner(owner, target);
+                        move_bullet(experience, owner, bullet_idx, to);
+                        on_change.emit(next);
+                    }
+                })
+            };
 
             let role_rows = roles
                 .iter()
@@ -100,10 +152,8 @@ pub fn experience_controller(props: &ExperienceControllerProps) -> Html {
                             let input: HtmlInputElement = e.target_unchecked_into();
                             let mut next = experiences.clone();
                             if let Some(experience) = next.get_mut(idx) {
-                                let mut roles = roles_for_display(experience);
-                                if let Some(role) = roles.get_mut(role_idx) {
+                                if let Some(role) = experience.roles.get_mut(role_idx) {
                                     role.title = input.value();
-                                    apply_roles_to_experience(experience, roles);
                                     on_change.emit(next);
                                 }
                             }
@@ -113,14 +163,11 @@ pub fn experience_controller(props: &ExperienceControllerProps) -> Html {
                     let update_dates = {
                         let experiences = props.value.clone();
                         let on_change = props.on_change.clone();
-                        Callback::from(move |e: InputEvent| {
-                            let input: HtmlInputElement = e.target_unchecked_into();
+                        Callback::from(move |dates: DateRange| {
                             let mut next = experiences.clone();
                             if let Some(experience) = next.get_mut(idx) {
-                                let mut roles = roles_for_display(experience);
-                                if let Some(role) = roles.get_mut(role_idx) {
-                                    role.dates = input.value();
-                                    apply_roles_to_experience(experience, roles);
+                                if let Some(role) = experience.roles.get_mut(role_idx) {
+                                    role.dates = dates;
                                     on_change.emit(next);
                                 }
                             }
@@ -134,10 +181,8 @@ pub fn experience_controller(props: &ExperienceControllerProps) -> Html {
                             let input: HtmlInputElement = e.target_unchecked_into();
                             let mut next = experiences.clone();
                             if let Some(experience) = next.get_mut(idx) {
-                                let mut roles = roles_for_display(experience);
-                                if let Some(role) = roles.get_mut(role_idx) {
+                                if let Some(role) = experience.roles.get_mut(role_idx) {
                                     role.location = input.value();
-                                    apply_roles_to_experience(experience, roles);
                                     on_change.emit(next);
                                 }
                             }
@@ -150,10 +195,22 @@ pub fn experience_controller(props: &ExperienceControllerProps) -> Html {
                         Callback::from(move |_| {
                             let mut next = experiences.clone();
                             if let Some(experience) = next.get_mut(idx) {
-                                let mut roles = roles_for_display(experience);
-                                if roles.len() > 1 && role_idx < roles.len() {
-                                    roles.remove(role_idx);
-                                    apply_roles_to_experience(experience, roles);
+                                if experience.roles.len() > 1 && role_idx < experience.roles.len() {
+                                    experience.roles.remove(role_idx);
+                                    on_change.emit(next);
+                                }
+                            }
+                        })
+                    };
+
+                    let update_role_bullets = {
+                        let experiences = props.value.clone();
+                        let on_change = props.on_change.clone();
+                        Callback::from(move |bullets: Vec<Bullet>| {
+                            let mut next = experiences.clone();
+                            if let Some(experience) = next.get_mut(idx) {
+                                if let Some(role) = experience.roles.get_mut(role_idx) {
+                                    role.bullets = bullets;
                                     on_change.emit(next);
                                 }
                             }
@@ -163,7 +220,7 @@ pub fn experience_controller(props: &ExperienceControllerProps) -> Html {
                     let remove_role_button = if roles.len() > 1 {
                         html! {
                             <button
-                                class="rounded-md px-3 py-2 text-xs font-semibold text-white bg-slate-700 hover:bg-slate-600"
+                                class={SMALL_BUTTON_CLASS}
                                 onclick={remove_role}
                             >
                                 {"Remove Role"}
@@ -173,9 +230,16 @@ pub fn experience_controller(props: &ExperienceControllerProps) -> Html {
                         html! {}
                     };
 
+                    let anchor = props.employment.then(|| {
+                        EntryRef::Role {
+                            experience: idx,
+                            role: role_idx,
+                        }
+                        .anchor_id()
+                    });
                     html! {
-                        <div class="border border-slate-800/80 rounded-lg p-0.5 my-0.5">
-                            <div class="grid grid-cols-1 md:grid-cols-3 gap-1">
+                        <div id={anchor} tabindex="-1" class="border border-slate-800/80 rounded-lg p-0.5 my-0.5">
+                            <div class="grid grid-cols-1 md:grid-cols-2 gap-1">
                                 <div class="relative flex-1">
                                     <input
                                         type="text"
@@ -185,15 +249,6 @@ pub fn experience_controller(props: &ExperienceControllerProps) -> Html {
                                     />
                                     <label class={LABEL_CLASS}> {"Role"} </label>
                                 </div>
-                                <div class="relative flex-1">
-                                    <input
-                                        type="text"
-                                        class={INPUT_CLASS}
-                                        value={role.dates.clone()}
-                                        oninput={update_dates}
-                                    />
-                                    <label class={LABEL_CLASS}> {"Date Range"} </label>
-                                </div>
                                 <div class="relative flex-1">
                                     <input
                                         type="text"
@@ -204,6 +259,14 @@ pub fn experience_controller(props: &ExperienceControllerProps) -> Html {
                                     <label class={LABEL_CLASS}> {"Location"} </label>
                                 </div>
                             </div>
+                            <DateRangeInput value={role.dates.clone()} on_change={update_dates} />
+                            <BulletListEditor
+                                label="Role Bullets"
+                                value={role.bullets.clone()}
+                                on_change={update_role_bullets}
+                                move_targets={move_targets(&owner_labels, role_idx + 1)}
+                                on_move={move_bullet_from(role_idx + 1)}
+                            />
                             <div class="mt-0 flex justify-end">
                                 {remove_role_button}
                             </div>
@@ -218,56 +281,51 @@ pub fn experience_controller(props: &ExperienceControllerProps) -> Html {
                 Callback::from(move |_| {
                     let mut next = experiences.clone();
                     if let Some(experience) = next.get_mut(idx) {
-                        let mut roles = roles_for_display(experience);
-                        roles.push(RolePeriod::default());
-                        apply_roles_to_experience(experience, roles);
+                        experience.roles.push(RolePeriod::default());
                         on_change.emit(next);
                     }
                 })
             };
 
-            let update_description = {
+            let update_bullets = {
                 let experiences = props.value.clone();
                 let on_change = props.on_change.clone();
-                Callback::from(move |e: InputEvent| {
-                    let input: HtmlTextAreaElement = e.target_unchecked_into();
+                Callback::from(move |bullets: Vec<Bullet>| {
                     let mut next = experiences.clone();
                     if let Some(experience) = next.get_mut(idx) {
-                        experience.description = input.value();
+                        experience.bullets = bullets;
                         on_change.emit(next);
                     }
                 })
             };
-            let description_input = html! {
-                <div class={INPUT_FIELD_WRAPPER_CLASS}>
-                <div class="relative">
-                <textarea
-                    name="description"
-                    class={TEXTAREA_CLASS}
-                    oninput={update_description}
-                    value={experience.description.clone()}
-                />
-                <label for={"description".to_string()}
-                       class={LABEL_CLASS} >
-                    {"Description"}
-                </label>
-                </div>
-                </div>
-            };
             html! {
                 <>
                     {employer_input}
                     {team_input}
+                    <TagsInput value={experience.tags.clone()} on_change={update_tags} />
                     <div class={INPUT_FIELD_WRAPPER_CLASS}>
                         <div class="flex items-center justify-between px-1 py-1">
-                            <div class="text-xs tracking-wide uppercase text-slate-400 font-semibold">{"Roles"}</div>
-                            <button class="rounded-md px-3 py-2 text-xs font-semibold text-slate-950 bg-emerald-400 hover:bg-emerald-300" onclick={add_role}>
+                            <div class={SUBSECTION_LABEL_CLASS}>{"Roles"}</div>
+                            <button class={SMALL_ADD_BUTTON_CLASS} onclick={add_role}>
                                 {"Add Role + Dates"}
                             </button>
                         </div>
                         {role_rows}
                     </div>
-                    {description_input}
+                    <BulletListEditor
+                        label="Shared Bullets"
+                        value={experience.bullets.clone()}
+                        on_change={update_bullets}
+                        move_targets={move_targets(&owner_labels, 0)}
+                        on_move={move_bullet_from(0)}
+                    />
+                    if props.employment {
+                        <TagsInput
+                            label="Tech Stack"
+                            value={experience.tech_stack.clone()}
+                            on_change={update_tech_stack}
+                        />
+                    }
                     <button
                         class={REMOVE_BUTTON_CLASS}
                         onclick={remove_experience}>
@@ -280,7 +338,7 @@ pub fn experience_controller(props: &ExperienceControllerProps) -> Html {
     html! {
         <>
             <div class={INPUT_SECTION_ROW_CLASS}>
-                <h5 class={INPUT_SECTION_CLASS}> {"Experiences"} </h5>
+                <h5 class={INPUT_SECTION_CLASS}> {props.title.clone()} </h5>
                 <button
                     class={ADD_BUTTON_CLASS}
                     onclick={add_experience}
@@ -339,57 +397,69 @@ where
     }
 }
 
-fn roles_for_display(experience: &Experience) -> Vec<RolePeriod> {
-    if !experience.roles.is_empty() {
-        return experience
-            .roles
-            .iter()
-            .map(|role| RolePeriod {
-                title: role.title.clone(),
-                dates: role.dates.clone(),
-                location: if role.location.is_empty() {
-                    experience.location.clone()
-                } else {
-                    role.location.clone()
-                },
-            })
-            .collect();
-    }
-
-    if experience.title.is_empty() && experience.dates.is_empty() && experience.location.is_empty()
-    {
-        vec![RolePeriod::default()]
-    } else {
-        vec![RolePeriod {
-            title: experience.title.clone(),
-            dates: experience.dates.clone(),
-            location: experience.location.clone(),
-        }]
-    }
+/// Labels of the bullet lists of an experience: the shared list followed by
+/// one list per role.
+fn bullet_owner_labels(roles: &[RolePeriod]) -> Vec<String> {
+    std::iter::once("Shared".to_string())
+        .chain(roles.iter().enumerate().map(|(idx, role)| {
+            if role.title.is_empty() {
+                format!("Role {}", idx + 1)
+            } else {
+                role.title.clone()
+            }
+        }))
+        .collect()
 }
 
-fn apply_roles_to_experience(experience: &mut Experience, roles: Vec<RolePeriod>) {
-    let normalized = if roles.is_empty() {
-        vec![RolePeriod::default()]
-    } else {
-        roles
+/// Moves a bullet between bullet lists, where owner `0` is the shared list
+/// and owner `n` is the list of role `n - 1`.
+fn move_bullet(experience: &mut Experience, from: usize, bullet_idx: usize, to: usize) {
+    let roles = &mut experience.roles;
+    let bullet = match from {
+        0 => {
+            if bullet_idx >= experience.bullets.len() {
+                return;
+            }
+            experience.bullets.remove(bullet_idx)
+        }
+        owner => match roles.get_mut(owner - 1) {
+            Some(role) if bullet_idx < role.bullets.len() => role.bullets.remove(bullet_idx),
+            _ => return,
+        },
     };
+    match to {
+        0 => experience.bullets.push(bullet),
+        owner => match roles.get_mut(owner - 1) {
+            Some(role) => role.bullets.push(bullet),
+            None => experience.bullets.push(bullet),
+        },
+    }
+}
 
-    experience.roles = normalized.clone();
-    if let Some(first) = normalized.first() {
-        experience.title = first.title.clone();
-        experience.dates = first.dates.clone();
-        experience.location = first.location.clone();
+/// Maps a move target index, which skips `owner`, back to an owner index.
+fn move_target_owner(owner: usize, target: usize) -> usize {
+    if target >= owner {
+        target + 1
     } else {
-        experience.title.clear();
-        experience.dates.clear();
-        experience.location.clear();
+        target
     }
 }
 
+fn move_targets(labels: &[String], owner: usize) -> Vec<String> {
+    labels
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| *idx != owner)
+        .map(|(_, label)| label.clone())
+        .collect()
+}
+
 #[derive(Properties, PartialEq)]
 pub struct Props {
     pub experiences: Vec<Experience>,
+    pub heading: String,
+    pub theme: Theme,
+    pub date_format: DateFormat,
 }
 
 #[function_component(ExperienceViewer)]
@@ -397,21 +467,21 @@ pub fn view_experience(props: &Props) -> Html {
     if props.experiences.is_empty() {
         return html! {};
     }
+    let today = current_month();
+    let styles = props.theme.styles();
     let experiences = props
         .experiences
         .iter()
         .map(|experience| {
-            let parsed = experience
-                .description
-                .split('\n')
-                .collect::<Vec<&str>>()
+            let parsed = render_bullets(&experience.bullets);
+            let tech_stack = if experience.tech_stack.is_empty() {
+                html! {}
+            } else {
+                render_bullets(&[Bullet::from(utilized_line(&experience.tech_stack).as_str())])
+            };
+            let roles = experience
+                .roles
                 .iter()
-                .map(|s| " - • ".to_string() + s)
-                .map(|s| markdown::to_html(&s))
-                .map(|html_str| Html::from_html_unchecked(html_str.into()))
-                .collect::<Html>();
-            let roles = roles_for_display(experience)
-                .into_iter()
                 .enumerate()
                 .map(|(idx, role)| {
                     let employer = if idx == 0 {
@@ -429,31 +499,36 @@ pub fn view_experience(props: &Props) -> Html {
                     } else {
                         html! { <span class="invisible"> {experience.team.clone()} </span> }
                     };
+                    let role_bullets = render_bullets(&role.bullets);
                     html! {
-                        <div class="grid grid-cols-[minmax(0,1fr)_auto] items-start gap-x-1 mt-0">
+                        <>
+                        <div class={styles.entry_row}>
                             <div class="flex gap-x-1 min-w-0">
                                 {employer}
                                 {employer_team_sep}
                                 {team}
                                 <span> {" | "} </span>
-                                <span> {role.title} </span>
+                                <span> {role.title.clone()} </span>
                             </div>
-                            <div class="flex gap-x-1 whitespace-nowrap">
-                                <span> {role.dates} </span>
+                            <div class={styles.entry_meta}>
+                                <span> {role.dates.format(&props.date_format, today)} </span>
                                 <span> {" | "} </span>
-                                <span> {role.location} </span>
+                                <span> {role.location.clone()} </span>
                             </div>
                         </div>
+                        {role_bullets}
+                        </>
                     }
                 })
                 .collect::<Html>();
             html! {
-                <div class="mb-3">
+                <div class={styles.entry_gap}>
                 <div>
                     {roles}
                 </div>
                 <div>
                     {parsed}
+                    {tech_stack}
                 </div>
                 </div>
             }
@@ -462,10 +537,38 @@ pub fn view_experience(props: &Props) -> Html {
     html! {
 
         <>
-        <h5 class={SECTION_HEADER_CLASS}> {"Experiences"} </h5>
+        <h5 class={props.theme.styles().section_header}> {props.heading.clone()} </h5>
         <div class="mx-2 my-2">
             {experiences}
         </div>
         </>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bullets_move_between_shared_and_role_lists() {
+        let mut experience = Experience {
+            roles: vec![RolePeriod::default(), RolePeriod::default()],
+            bullets: vec![Bullet::from("Promoted"), Bullet::from("Interned")],
+            ..Experience::default()
+        };
+
+        move_bullet(&mut experience, 0, 1, move_target_owner(0, 1));
+        assert_eq!(experience.bullets, vec![Bullet::from("Promoted")]);
+        assert_eq!(experience.roles[1].bullets, vec![Bullet::from("Interned")]);
+
+        move_bullet(&mut experience, 2, 0, move_target_owner(2, 0));
+        assert_eq!(experience.bullets.len(), 2);
+        assert!(experience.roles[1].bullets.is_empty());
+
+        // A stale index from a double click leaves everything in place.
+        let before = experience.clone();
+        move_bullet(&mut experience, 0, 2, 1);
+        move_bullet(&mut experience, 1, 0, 0);
+        assert_eq!(experience, before);
+    }
+}
@@ -0,0 +1,539 @@
+use serde_json::{json, Map, Value};
+use wasm_bindgen::JsCast;
+use web_sys::{window, HtmlElement};
+use yew::prelude::*;
+
+use super::basic::{Basic, LinkKind};
+use super::bullets::Bullet;
+use super::dates::{DateEnd, DateRange, PartialDate};
+use super::experiences::Experience;
+use super::html_utils::{
+    INPUT_DIVIDER_CLASS, INPUT_FIELD_WRAPPER_CLASS, INPUT_SECTION_CLASS, INPUT_SECTION_ROW_CLASS,
+    SMALL_BUTTON_CLASS,
+};
+use super::languages::Proficiency;
+use super::references::ReferenceMode;
+use super::state::AppState;
+
+/// Drops empty strings, arrays, objects and nulls, so exports only carry
+/// fields that were filled in.
+fn prune(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| (key, prune(value)))
+                .filter(|(_, value)| !is_empty(value))
+                .collect::<Map<_, _>>(),
+        ),
+        Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .map(prune)
+                .filter(|value| !is_empty(value))
+                .collect(),
+        ),
+        Value::String(text) => Value::String(text.trim().to_string()),
+        value => value,
+    }
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(text) => text.is_empty(),
+        Value::Array(items) => items.is_empty(),
+        Value::Object(map) => map.is_empty(),
+        _ => false,
+    }
+}
+
+fn iso_date(date: &PartialDate) -> String {
+    match date.day {
+        Some(day) => format!("{:04}-{:02}-{:02}", date.year, date.month, day),
+        None => format!("{:04}-{:02}", date.year, date.month),
+    }
+}
+
+fn iso_end(dates: &DateRange) -> Option<String> {
+    match dates.end {
+        Some(DateEnd::On(date)) => Some(iso_date(&date)),
+        Some(DateEnd::Present) | None => None,
+    }
+}
+
+fn visible_bullets<'a>(bullets: impl IntoIterator<Item = &'a Bullet>) -> Vec<String> {
+    bullets
+        .into_iter()
+        .filter(|bullet| !bullet.hidden)
+        .map(|bullet| bullet.text.clone())
+        .collect()
+}
+
+/// Splits "Given Names Surname", dropping quoted nicknames.
+fn split_person_name(name: &str) -> (String, String) {
+    let mut words = name
+        .split_whitespace()
+        .filter(|word| !word.starts_with('"'))
+        .collect::<Vec<_>>();
+    let surname = words.pop().unwrap_or_default();
+    (words.join(" "), surname.to_string())
+}
+
+fn website(basic: &Basic) -> Option<&str> {
+    basic
+        .links
+        .iter()
+        .find(|link| matches!(link.kind, LinkKind::Website | LinkKind::Portfolio))
+        .map(|link| link.url.as_str())
+}
+
+/// A role's bullets; those shared by every role are listed with the first one.
+fn role_bullets(experience: &Experience, idx: usize) -> Vec<String> {
+    let mut bullets = if idx == 0 {
+        visible_bullets(&experience.bullets)
+    } else {
+        vec![]
+    };
+    if let Some(role) = experience.roles.get(idx) {
+        bullets.extend(visible_bullets(&role.bullets));
+    }
+    bullets
+}
+
+/// One JSON Resume entry per role, with the organization under `name_key`.
+fn role_entries(experiences: &[Experience], name_key: &str) -> Vec<Value> {
+    experiences
+        .iter()
+        .flat_map(|experience| {
+            experience.roles.iter().enumerate().map(move |(idx, role)| {
+                let highlights = role_bullets(experience, idx);
+                json!({
+                    name_key: experience.employer,
+                    "position": role.title,
+                    "location": role.location,
+                    "startDate": role.dates.start.as_ref().map(iso_date),
+                    "endDate": iso_end(&role.dates),
+                    "highlights": highlights,
+                })
+            })
+        })
+        .collect()
+}
+
+/// The resume as a [JSON Resume](https://jsonresume.org/schema) document.
+pub fn to_json_resume(state: &AppState) -> Value {
+    let basic = &state.basic;
+    let (city, region) = match basic.location.split_once(',') {
+        Some((city, region)) => (city.trim(), region.trim()),
+        None => (basic.location.trim(), ""),
+    };
+    let profiles = basic
+        .links
+        .iter()
+        .filter(|link| !matches!(link.kind, LinkKind::Website | LinkKind::Portfolio))
+        .map(|link| json!({ "network": link.display_label(), "url": link.url }))
+        .collect::<Vec<_>>();
+
+    let education = state
+        .educations
+        .iter()
+        .map(|education| {
+            json!({
+                "institution": education.school,
+                "area": education.major,
+                "studyType": education.degree,
+                "startDate": education.dates.start.as_ref().map(iso_date),
+                "endDate": iso_end(&education.dates),
+                "score": education.gpa,
+                "courses": education.coursework,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let skills = state
+        .skills
+        .iter()
+        .map(|category| {
+            json!({
+                "name": category.category,
+                "keywords": category.skills.iter().map(|skill| &skill.name).collect::<Vec<_>>(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let languages = state
+        .languages
+        .iter()
+        .map(|language| {
+            let fluency = match language.proficiency.cefr() {
+                Some(code) => format!("{} ({})", language.proficiency.description(), code),
+                None => "Native speaker".to_string(),
+            };
+            json!({ "language": language.name, "fluency": fluency })
+        })
+        .collect::<Vec<_>>();
+
+    let projects = state
+        .projects
+        .iter()
+        .map(|project| {
+            json!({
+                "name": project.name,
+                "description": project.description,
+                "roles": [project.role],
+                "startDate": project.dates.start.as_ref().map(iso_date),
+                "endDate": iso_end(&project.dates),
+                "highlights": visible_bullets(&project.bullets),
+                "keywords": project.tech_stack,
+                "url": project.links.first().map(|link| &link.url),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let certificates = state
+        .certifications
+        .iter()
+        .map(|certification| {
+            json!({
+                "name": certification.name,
+                "issuer": certification.issuer,
+                "date": certification.issued.as_ref().map(iso_date),
+                "url": certification.url,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let publications = state
+        .publications
+        .iter()
+        .map(|publication| {
+            let url = match publication.doi.trim() {
+                "" => publication.url.clone(),
+                doi => format!("https://doi.org/{}", doi),
+            };
+            json!({
+                "name": publication.title,
+                "publisher": publication.venue,
+                "releaseDate": publication.year.map(|year| year.to_string()),
+                "url": url,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // Like the preview, only list references when their details are shown.
+    let references = match state.reference_settings.mode {
+        ReferenceMode::Full => state
+            .references
+            .iter()
+            .map(|reference| {
+                let role = [reference.relationship.trim(), reference.company.trim()]
+                    .into_iter()
+                    .filter(|part| !part.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                json!({ "name": reference.name, "reference": role })
+            })
+            .collect::<Vec<_>>(),
+        ReferenceMode::OnRequest | ReferenceMode::Hidden => vec![],
+    };
+    let image = if basic.photo.is_empty() {
+        String::new()
+    } else {
+        basic.photo.data_url()
+    };
+
+    prune(json!({
+        "$schema": "https://raw.githubusercontent.com/jsonresume/resume-schema/v1.0.0/schema.json",
+        "basics": {
+            "name": basic.name,
+            "label": basic.headline,
+            "image": image,
+            "email": basic.email,
+            "phone": basic.phone,
+            "url": website(basic),
+            "summary": state.summary,
+            "location": { "city": city, "region": region },
+            "profiles": profiles,
+        },
+        "work": role_entries(&state.experiences, "name"),
+        "volunteer": role_entries(&state.volunteering, "organization"),
+        "education": education,
+        "skills": skills,
+        "languages": languages,
+        "projects": projects,
+        "certificates": certificates,
+        "publications": publications,
+        "references": references,
+    }))
+}
+
+fn europass_date(date: &PartialDate) -> Value {
+    json!({ "Year": date.year, "Month": format!("--{:02}", date.month) })
+}
+
+fn europass_period(dates: &DateRange) -> Value {
+    let to = match dates.end {
+        Some(DateEnd::On(date)) => Some(europass_date(&date)),
+        Some(DateEnd::Present) | None => None,
+    };
+    let current = dates.end == Some(DateEnd::Present);
+    json!({
+        "From": dates.start.as_ref().map(europass_date),
+        "To": to,
+        "Current": current,
+    })
+}
+
+/// The resume as a Europass CV (SkillsPassport JSON).
+pub fn to_europass(state: &AppState) -> Value {
+    let basic = &state.basic;
+    let (first_name, surname) = split_person_name(&basic.name);
+
+    let work_experience = state
+        .experiences
+        .iter()
+        .flat_map(|experience| {
+            experience.roles.iter().enumerate().map(move |(idx, role)| {
+                let activities = role_bullets(experience, idx).join("\n");
+                json!({
+                    "Period": europass_period(&role.dates),
+                    "Position": { "Label": role.title },
+                    "Activities": activities,
+                    "Employer": {
+                        "Name": experience.employer,
+                        "ContactInfo": { "Address": { "Contact": { "Municipality": role.location } } },
+                    },
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let education = state
+        .educations
+        .iter()
+        .map(|education| {
+            let title = match education.major.trim() {
+                "" => education.degree.clone(),
+                major => format!("{} in {}", education.degree, major),
+            };
+            json!({
+                "Period": europass_period(&education.dates),
+                "Title": title,
+                "Activities": education.coursework.join(", "),
+                "Organisation": { "Name": education.school },
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let (native, foreign): (Vec<_>, Vec<_>) = state
+        .languages
+        .iter()
+        .partition(|language| language.proficiency == Proficiency::Native);
+    let mother_tongue = native
+        .iter()
+        .map(|language| json!({ "Description": { "Label": language.name } }))
+        .collect::<Vec<_>>();
+    let foreign_language = foreign
+        .iter()
+        .map(|language| {
+            let level = language.proficiency.cefr().unwrap_or_default();
+            json!({
+                "Description": { "Label": language.name },
+                "ProficiencyLevel": {
+                    "Listening": level,
+                    "Reading": level,
+                    "SpokenInteraction": level,
+                    "SpokenProduction": level,
+                    "Writing": level,
+                },
+                "Certificate": [{ "Title": language.certificate }],
+            })
+        })
+        .collect::<Vec<_>>();
+
+    prune(json!({
+        "SkillsPassport": {
+            "Locale": "en",
+            "LearnerInfo": {
+                "Identification": {
+                    "PersonName": { "FirstName": first_name, "Surname": surname },
+                    "ContactInfo": {
+                        "Address": { "Contact": { "Municipality": basic.location } },
+                        "Email": { "Contact": basic.email },
+                        "Telephone": [{ "Contact": basic.phone }],
+                        "Website": basic
+                            .links
+                            .iter()
+                            .map(|link| json!({ "Contact": link.url }))
+                            .collect::<Vec<_>>(),
+                    },
+                },
+                "Headline": {
+                    "Type": { "Code": "personal_statement", "Label": "Personal statement" },
+                    "Description": { "Label": state.summary },
+                },
+                "WorkExperience": work_experience,
+                "Education": education,
+                "Skills": {
+                    "Linguistic": {
+                        "MotherTongue": mother_tongue,
+                        "ForeignLanguage": foreign_language,
+                    },
+                },
+            },
+        },
+    }))
+}
+
+/// Saves `contents` as a file through a temporary download link.
+fn download(file_name: &str, contents: &str) {
+    let Some(document) = window().and_then(|window| window.document()) else {
+        return;
+    };
+    let Ok(link) = document.create_element("a") else {
+        return;
+    };
+    let href = format!(
+        "data:application/json;charset=utf-8,{}",
+        js_sys::encode_uri_component(contents)
+    );
+    if link.set_attribute("href", &href).is_err()
+        || link.set_attribute("download", file_name).is_err()
+    {
+        return;
+    }
+    if let Ok(link) = link.dyn_into::<HtmlElement>() {
+        link.click();
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ExportControllerProps {
+    /// The resume as currently shown, with the active variant applied.
+    pub state: AppState,
+}
+
+#[function_component(ExportController)]
+pub fn export_controller(props: &ExportControllerProps) -> Html {
+    let export = |file_name: &'static str, convert: fn(&AppState) -> Value| {
+        let state = props.state.clone();
+        Callback::from(move |_| {
+            let document = convert(&state);
+            if let Ok(contents) = serde_json::to_string_pretty(&document) {
+                download(file_name, &contents);
+            }
+        })
+    };
+
+    html! {
+        <>
+            <div class={INPUT_SECTION_ROW_CLASS}>
+                <h5 class={INPUT_SECTION_CLASS}> {"Export"} </h5>
+            </div>
+            <div class={INPUT_DIVIDER_CLASS}></div>
+            <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                <div class="flex gap-x-2 px-1 py-1">
+                    <button
+                        class={SMALL_BUTTON_CLASS}
+                        onclick={export("resume.json", to_json_resume)}
+                    >
+                        {"JSON Resume"}
+                    </button>
+                    <button
+                        class={SMALL_BUTTON_CLASS}
+                        onclick={export("europass.json", to_europass)}
+                    >
+                        {"Europass"}
+                    </button>
+                </div>
+            </div>
+        </>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::experiences::RolePeriod;
+    use crate::pages::languages::Language;
+    use crate::pages::state::default_seed_state;
+
+    fn with_languages() -> AppState {
+        let mut state = default_seed_state();
+        state.summary = "Backend engineer.".to_string();
+        state.languages = vec![
+            Language {
+                name: "Mandarin".to_string(),
+                proficiency: Proficiency::Native,
+                ..Default::default()
+            },
+            Language {
+                name: "French".to_string(),
+                proficiency: Proficiency::B2,
+                certificate: "DELF B2".to_string(),
+                ..Default::default()
+            },
+        ];
+        state
+    }
+
+    #[test]
+    fn exports_json_resume() {
+        let resume = to_json_resume(&with_languages());
+        assert_eq!(resume["basics"]["summary"], "Backend engineer.");
+        assert_eq!(resume["languages"][0]["fluency"], "Native speaker");
+        assert_eq!(
+            resume["languages"][1],
+            json!({ "language": "French", "fluency": "Upper intermediate (B2)" })
+        );
+        // Fields left blank are not exported.
+        assert!(resume.get("certificates").is_none());
+    }
+
+    #[test]
+    fn exports_volunteering_apart_from_work() {
+        let mut state = default_seed_state();
+        let work = to_json_resume(&state)["work"].clone();
+        state.volunteering = vec![Experience {
+            employer: "Code for Greensboro".to_string(),
+            roles: vec![RolePeriod {
+                title: "Organizer".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }];
+
+        let resume = to_json_resume(&state);
+        assert_eq!(resume["work"], work);
+        assert_eq!(
+            resume["volunteer"],
+            json!([{ "organization": "Code for Greensboro", "position": "Organizer" }])
+        );
+    }
+
+    #[test]
+    fn exports_europass_language_levels() {
+        let europass = to_europass(&with_languages());
+        let linguistic = &europass["SkillsPassport"]["LearnerInfo"]["Skills"]["Linguistic"];
+        assert_eq!(
+            linguistic["MotherTongue"][0]["Description"]["Label"],
+            "Mandarin"
+        );
+        let french = &linguistic["ForeignLanguage"][0];
+        assert_eq!(french["ProficiencyLevel"]["Writing"], "B2");
+        assert_eq!(french["Certificate"][0]["Title"], "DELF B2");
+    }
+
+    #[test]
+    fn exports_europass_shared_bullets_with_first_role() {
+        let state = default_seed_state();
+        let europass = to_europass(&state);
+        let work = &europass["SkillsPassport"]["LearnerInfo"]["WorkExperience"];
+        let activities = work[0]["Activities"].as_str().unwrap_or_default();
+        assert!(!activities.is_empty());
+        assert_eq!(
+            activities,
+            role_bullets(&state.experiences[0], 0).join("\n")
+        );
+    }
+}
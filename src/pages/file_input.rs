@@ -0,0 +1,66 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gloo_file::callbacks::{read_as_bytes, FileReader};
+use gloo_file::File;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use super::html_utils::{INPUT_CLASS, LABEL_CLASS};
+
+/// A file read entirely in the browser.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LoadedFile {
+    pub name: String,
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct FilePickerProps {
+    pub label: AttrValue,
+    /// The `accept` attribute, e.g. ".json,application/json".
+    #[prop_or_default]
+    pub accept: AttrValue,
+    pub on_load: Callback<Result<LoadedFile, String>>,
+}
+
+/// A file input that reads the chosen file locally; nothing is uploaded.
+#[function_component(FilePicker)]
+pub fn file_picker(props: &FilePickerProps) -> Html {
+    // Dropping the reader would abort the read, so keep the latest one around.
+    let reader: Rc<RefCell<Option<FileReader>>> = use_mut_ref(|| None);
+
+    let on_change = {
+        let reader = reader.clone();
+        let on_load = props.on_load.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let Some(file) = input.files().and_then(|files| files.get(0)) else {
+                return;
+            };
+            // Allow picking the same file again.
+            input.set_value("");
+            let file = File::from(file);
+            let name = file.name();
+            let on_load = on_load.clone();
+            *reader.borrow_mut() = Some(read_as_bytes(&file, move |result| {
+                on_load.emit(
+                    result
+                        .map(|bytes| LoadedFile { name, bytes })
+                        .map_err(|e| e.to_string()),
+                );
+            }));
+        })
+    };
+
+    html! {
+        <div class="relative">
+            <input type="file"
+                   class={INPUT_CLASS}
+                   accept={props.accept.clone()}
+                   onchange={on_change}
+            />
+            <label class={LABEL_CLASS}> {props.label.clone()} </label>
+        </div>
+    }
+}
@@ -0,0 +1,257 @@
+use serde::Deserialize;
+use yew::prelude::*;
+
+use super::file_input::{FilePicker, LoadedFile};
+use super::html_utils::{INPUT_FIELD_WRAPPER_CLASS, SMALL_ADD_BUTTON_CLASS, SMALL_BUTTON_CLASS};
+use super::open_source::{ContributionStatus, OpenSource};
+
+#[derive(Deserialize)]
+struct SearchPage {
+    items: Vec<SearchItem>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SearchDump {
+    Page(SearchPage),
+    Pages(Vec<SearchPage>),
+    Items(Vec<SearchItem>),
+}
+
+#[derive(Deserialize)]
+struct SearchItem {
+    html_url: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    state: String,
+    #[serde(default)]
+    pull_request: Option<PullRequestInfo>,
+}
+
+#[derive(Deserialize)]
+struct PullRequestInfo {
+    #[serde(default)]
+    merged_at: Option<String>,
+}
+
+/// Parses a saved `GET /search/issues` response: a single page, a list of
+/// pages, or a bare list of items.
+pub fn parse_github_search(json: &str) -> Result<Vec<OpenSource>, String> {
+    let dump: SearchDump =
+        serde_json::from_str(json).map_err(|_| "Not a GitHub search API response".to_string())?;
+    let items = match dump {
+        SearchDump::Page(page) => page.items,
+        SearchDump::Pages(pages) => pages.into_iter().flat_map(|page| page.items).collect(),
+        SearchDump::Items(items) => items,
+    };
+    Ok(items
+        .into_iter()
+        .map(|item| {
+            let merged = item
+                .pull_request
+                .as_ref()
+                .is_some_and(|pr| pr.merged_at.is_some());
+            let status = match item.state.as_str() {
+                "open" => Some(ContributionStatus::Open),
+                "closed" if merged => Some(ContributionStatus::Merged),
+                "closed" => Some(ContributionStatus::Closed),
+                _ => None,
+            };
+            OpenSource {
+                description: item.title.trim().to_string(),
+                status,
+                ..OpenSource::from_url(&item.html_url)
+            }
+        })
+        .collect())
+}
+
+fn url_key(url: &str) -> String {
+    url.trim().trim_end_matches('/').to_lowercase()
+}
+
+/// Whether an imported entry's URL is already listed.
+pub fn is_listed(existing: &[OpenSource], candidate: &OpenSource) -> bool {
+    let key = url_key(&candidate.url);
+    existing.iter().any(|known| url_key(&known.url) == key)
+}
+
+#[derive(Properties, PartialEq)]
+pub struct GitHubImportProps {
+    pub existing: Vec<OpenSource>,
+    pub on_import: Callback<Vec<OpenSource>>,
+}
+
+/// Reviews contributions from a saved GitHub search before adding them.
+#[function_component(GitHubImport)]
+pub fn github_import(props: &GitHubImportProps) -> Html {
+    // Candidates and whether each is selected for import.
+    let candidates = use_state(Vec::<(OpenSource, bool)>::new);
+    let error = use_state(String::new);
+
+    let on_load = {
+        let candidates = candidates.clone();
+        let error = error.clone();
+        let existing = props.existing.clone();
+        Callback::from(move |file: Result<LoadedFile, String>| {
+            let parsed =
+                file.and_then(|file| parse_github_search(&String::from_utf8_lossy(&file.bytes)));
+            match parsed {
+                Ok(found) => {
+                    let mut seen: Vec<OpenSource> = vec![];
+                    let mut next = vec![];
+                    for candidate in found {
+                        if is_listed(&seen, &candidate) {
+                            continue;
+                        }
+                        let fresh = !is_listed(&existing, &candidate);
+                        seen.push(candidate.clone());
+                        next.push((candidate, fresh));
+                    }
+                    error.set(if next.is_empty() {
+                        "No pull requests or issues found in that file.".to_string()
+                    } else {
+                        String::new()
+                    });
+                    candidates.set(next);
+                }
+                Err(message) => {
+                    error.set(message);
+                    candidates.set(vec![]);
+                }
+            }
+        })
+    };
+
+    let rows = candidates
+        .iter()
+        .enumerate()
+        .map(|(idx, (candidate, selected))| {
+            let listed = is_listed(&props.existing, candidate);
+            let toggle = {
+                let candidates = candidates.clone();
+                Callback::from(move |_| {
+                    let mut next = (*candidates).clone();
+                    if let Some((_, selected)) = next.get_mut(idx) {
+                        *selected = !*selected;
+                    }
+                    candidates.set(next);
+                })
+            };
+            html! {
+                <label class="flex items-start gap-x-1 px-1 py-0.5 text-xs text-slate-300">
+                    <input type="checkbox"
+                           checked={*selected}
+                           disabled={listed}
+                           onclick={toggle}
+                    />
+                    <span>
+                        <span class="font-semibold"> {&candidate.name} </span>
+                        {format!(" {}", candidate.description)}
+                        if listed {
+                            <span class="text-slate-500"> {" (already added)"} </span>
+                        }
+                    </span>
+                </label>
+            }
+        })
+        .collect::<Html>();
+
+    let add_selected = {
+        let candidates = candidates.clone();
+        let existing = props.existing.clone();
+        let on_import = props.on_import.clone();
+        Callback::from(move |_| {
+            let mut next = existing.clone();
+            next.extend(
+                candidates
+                    .iter()
+                    .filter(|(candidate, selected)| *selected && !is_listed(&existing, candidate))
+                    .map(|(candidate, _)| candidate.clone()),
+            );
+            on_import.emit(next);
+            candidates.set(vec![]);
+        })
+    };
+    let cancel = {
+        let candidates = candidates.clone();
+        Callback::from(move |_| candidates.set(vec![]))
+    };
+    let selected = candidates
+        .iter()
+        .filter(|(candidate, selected)| *selected && !is_listed(&props.existing, candidate))
+        .count();
+
+    html! {
+        <div class={INPUT_FIELD_WRAPPER_CLASS}>
+            <FilePicker
+                label="Import GitHub Search JSON"
+                accept=".json,application/json"
+                on_load={on_load}
+            />
+            if !error.is_empty() {
+                <p class="px-1 pt-1 text-xs text-rose-400"> {(*error).clone()} </p>
+            }
+            if !candidates.is_empty() {
+                <div class="max-h-64 overflow-y-auto py-1">
+                    {rows}
+                </div>
+                <div class="flex justify-end gap-x-2">
+                    <button class={SMALL_BUTTON_CLASS} onclick={cancel}> {"Cancel"} </button>
+                    <button class={SMALL_ADD_BUTTON_CLASS} onclick={add_selected}>
+                        {format!("Add {} Selected", selected)}
+                    </button>
+                </div>
+            }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_search_results_and_flags_listed_urls() {
+        let json = r#"{
+            "total_count": 3,
+            "incomplete_results": false,
+            "items": [
+                {
+                    "html_url": "https://github.com/kafka-rust/kafka-rust/pull/222",
+                    "title": "Support SASL authentication",
+                    "state": "closed",
+                    "pull_request": { "merged_at": "2023-01-02T00:00:00Z" }
+                },
+                {
+                    "html_url": "https://github.com/gleam-lang/stdlib/pull/769",
+                    "title": "Add list.window",
+                    "state": "closed",
+                    "pull_request": { "merged_at": null }
+                },
+                {
+                    "html_url": "https://github.com/gleam-lang/stdlib/issues/770",
+                    "title": "Docs typo",
+                    "state": "open"
+                }
+            ]
+        }"#;
+
+        let found = parse_github_search(json).expect("valid search response");
+        assert_eq!(found.len(), 3);
+        assert_eq!(found[0].name, "kafka-rust #222");
+        assert_eq!(found[0].description, "Support SASL authentication");
+        assert_eq!(found[0].status, Some(ContributionStatus::Merged));
+        assert_eq!(found[1].status, Some(ContributionStatus::Closed));
+        assert_eq!(found[2].status, Some(ContributionStatus::Open));
+
+        let existing = [OpenSource::from_url(
+            "https://github.com/kafka-rust/kafka-rust/pull/222/",
+        )];
+        assert!(is_listed(&existing, &found[0]));
+        assert!(!is_listed(&existing, &found[1]));
+
+        assert!(parse_github_search(r#"{"message": "Bad credentials"}"#).is_err());
+    }
+}
@@ -8,7 +8,9 @@ pub static INPUT_SECTION_CLASS: &str = "sidebar-section-title";
 pub static INPUT_DIVIDER_CLASS: &str = "sidebar-divider";
 pub static INPUT_FIELD_WRAPPER_CLASS: &str = "sidebar-field";
 pub static LABEL_CLASS: &str = "sidebar-label";
-
-// rusume
-pub static SECTION_HEADER_CLASS: &str =
-    "text-2xl font-bold text-left w-full border-b-2 border-black mt-2";
+pub static SMALL_BUTTON_CLASS: &str =
+    "rounded-md px-3 py-2 text-xs font-semibold text-white bg-slate-700 hover:bg-slate-600";
+pub static SMALL_ADD_BUTTON_CLASS: &str =
+    "rounded-md px-3 py-2 text-xs font-semibold text-slate-950 bg-emerald-400 hover:bg-emerald-300";
+pub static SUBSECTION_LABEL_CLASS: &str =
+    "text-xs tracking-wide uppercase text-slate-400 font-semibold";
@@ -0,0 +1,310 @@
+use serde::{Deserialize, Serialize};
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use super::html_utils::{
+    ADD_BUTTON_CLASS, INPUT_CLASS, INPUT_DIVIDER_CLASS, INPUT_FIELD_WRAPPER_CLASS,
+    INPUT_SECTION_CLASS, INPUT_SECTION_ROW_CLASS, LABEL_CLASS, REMOVE_BUTTON_CLASS,
+};
+use super::themes::Theme;
+use super::variants::TagsInput;
+
+/// CEFR levels, plus native speakers.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum Proficiency {
+    A1,
+    A2,
+    B1,
+    #[default]
+    B2,
+    C1,
+    C2,
+    Native,
+}
+
+impl Proficiency {
+    pub fn all() -> [Proficiency; 7] {
+        [
+            Proficiency::Native,
+            Proficiency::C2,
+            Proficiency::C1,
+            Proficiency::B2,
+            Proficiency::B1,
+            Proficiency::A2,
+            Proficiency::A1,
+        ]
+    }
+
+    /// The CEFR code, or `None` for native speakers.
+    pub fn cefr(&self) -> Option<&'static str> {
+        match self {
+            Proficiency::A1 => Some("A1"),
+            Proficiency::A2 => Some("A2"),
+            Proficiency::B1 => Some("B1"),
+            Proficiency::B2 => Some("B2"),
+            Proficiency::C1 => Some("C1"),
+            Proficiency::C2 => Some("C2"),
+            Proficiency::Native => None,
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Proficiency::A1 => "Beginner",
+            Proficiency::A2 => "Elementary",
+            Proficiency::B1 => "Intermediate",
+            Proficiency::B2 => "Upper intermediate",
+            Proficiency::C1 => "Advanced",
+            Proficiency::C2 => "Proficient",
+            Proficiency::Native => "Native",
+        }
+    }
+
+    /// "C1 · Advanced", or "Native".
+    pub fn label(&self) -> String {
+        match self.cefr() {
+            Some(code) => format!("{} · {}", code, self.description()),
+            None => self.description().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct Language {
+    pub name: String,
+    #[serde(default)]
+    pub proficiency: Proficiency,
+    /// e.g. "DELF B2" or "IELTS 8.0".
+    #[serde(default)]
+    pub certificate: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+pub enum LanguageField {
+    Name(String),
+    Certificate(String),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct LanguageControllerProps {
+    pub value: Vec<Language>,
+    pub on_change: Callback<Vec<Language>>,
+}
+
+#[function_component(LanguageController)]
+pub fn language_controller(props: &LanguageControllerProps) -> Html {
+    let add_language = {
+        let languages = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_| {
+            let mut next = languages.clone();
+            next.push(Language::default());
+            on_change.emit(next);
+        })
+    };
+
+    let inputs = props
+        .value
+        .iter()
+        .enumerate()
+        .map(|(idx, language)| {
+            let remove_language = {
+                let languages = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |_| {
+                    let mut next = languages.clone();
+                    if idx < next.len() {
+                        next.remove(idx);
+                        on_change.emit(next);
+                    }
+                })
+            };
+
+            let name_input = make_input(
+                props,
+                idx,
+                "Language".to_string(),
+                language.name.clone(),
+                LanguageField::Name,
+            );
+            let certificate_input = make_input(
+                props,
+                idx,
+                "Certificate".to_string(),
+                language.certificate.clone(),
+                LanguageField::Certificate,
+            );
+
+            let update_proficiency = {
+                let languages = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |e: Event| {
+                    let select: HtmlSelectElement = e.target_unchecked_into();
+                    let mut next = languages.clone();
+                    let proficiency = Proficiency::all()
+                        .into_iter()
+                        .find(|level| format!("{:?}", level) == select.value());
+                    if let (Some(language), Some(proficiency)) = (next.get_mut(idx), proficiency) {
+                        language.proficiency = proficiency;
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let proficiency_options = Proficiency::all()
+                .into_iter()
+                .map(|level| {
+                    html! {
+                        <option
+                            value={format!("{:?}", level)}
+                            selected={language.proficiency == level}
+                        >
+                            {level.label()}
+                        </option>
+                    }
+                })
+                .collect::<Html>();
+
+            let update_tags = {
+                let languages = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |tags: Vec<String>| {
+                    let mut next = languages.clone();
+                    if let Some(language) = next.get_mut(idx) {
+                        language.tags = tags;
+                        on_change.emit(next);
+                    }
+                })
+            };
+            html! {
+                <>
+                    <div class="flex">
+                        <div class="w-1/2">
+                            {name_input}
+                        </div>
+                        <div class="w-1/2">
+                            <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                                <div class="relative">
+                                    <select class={INPUT_CLASS} onchange={update_proficiency}>
+                                        {proficiency_options}
+                                    </select>
+                                    <label class={LABEL_CLASS}> {"Proficiency"} </label>
+                                </div>
+                            </div>
+                        </div>
+                    </div>
+                    {certificate_input}
+                    <TagsInput value={language.tags.clone()} on_change={update_tags} />
+                    <button
+                        class={REMOVE_BUTTON_CLASS}
+                        onclick={remove_language}
+                    >
+                        {"Remove"}
+                    </button>
+                </>
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <>
+            <div class={INPUT_SECTION_ROW_CLASS}>
+                <h5 class={INPUT_SECTION_CLASS}> {"Languages"} </h5>
+                <button
+                    class={ADD_BUTTON_CLASS}
+                    onclick={add_language}
+                >
+                    {"Add New"}
+                </button>
+            </div>
+            <div class={INPUT_DIVIDER_CLASS}></div>
+            {inputs}
+        </>
+    }
+}
+
+fn make_input<F>(
+    props: &LanguageControllerProps,
+    idx: usize,
+    name: String,
+    value: String,
+    cons: F,
+) -> Html
+where
+    F: Fn(String) -> LanguageField + 'static,
+{
+    let languages = props.value.clone();
+    let on_change = props.on_change.clone();
+    let callback = Callback::from(move |e: InputEvent| {
+        let input: HtmlInputElement = e.target_unchecked_into();
+        let mut next = languages.clone();
+        if let Some(language) = next.get_mut(idx) {
+            match cons(input.value()) {
+                LanguageField::Name(name) => language.name = name,
+                LanguageField::Certificate(certificate) => language.certificate = certificate,
+            }
+            on_change.emit(next);
+        }
+    });
+    html! {
+    <div class={INPUT_FIELD_WRAPPER_CLASS}>
+        <div class="relative">
+            <input type="text"
+                   id={name.clone()}
+                   oninput={callback}
+                   class={INPUT_CLASS}
+                   value={value}
+            />
+            <label for={name.clone()}
+                   class={LABEL_CLASS}>
+                {name}
+            </label>
+        </div>
+    </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub languages: Vec<Language>,
+    pub heading: String,
+    pub theme: Theme,
+}
+
+#[function_component(LanguageViewer)]
+pub fn view_language(props: &Props) -> Html {
+    if props.languages.is_empty() {
+        return html! {};
+    }
+    let count = props.languages.len();
+    let languages = props
+        .languages
+        .iter()
+        .enumerate()
+        .map(|(idx, language)| {
+            let level = match language.proficiency.cefr() {
+                Some(code) => code.to_string(),
+                None => language.proficiency.description().to_string(),
+            };
+            let details = match language.certificate.trim() {
+                "" => level,
+                certificate => format!("{}, {}", level, certificate),
+            };
+            html! {
+                <span>
+                    <span class="font-semibold"> {&language.name} </span>
+                    {format!(" ({})", details)}
+                    {if idx + 1 < count { " · " } else { "" }}
+                </span>
+            }
+        })
+        .collect::<Html>();
+    html! {
+        <>
+            <h5 class={props.theme.styles().section_header}> {props.heading.clone()} </h5>
+            <p class="mx-2 my-1">
+                {languages}
+            </p>
+        </>
+    }
+}
@@ -0,0 +1,619 @@
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+
+use yew::prelude::*;
+
+use super::basic::{Basic, ContactLink, LinkKind};
+use super::bullets::bullets_from_lines;
+use super::dates::DateRange;
+use super::education::Education;
+use super::experiences::{Experience, RolePeriod};
+use super::file_input::{FilePicker, LoadedFile};
+use super::html_utils::{
+    INPUT_DIVIDER_CLASS, INPUT_FIELD_WRAPPER_CLASS, INPUT_SECTION_CLASS, INPUT_SECTION_ROW_CLASS,
+    SMALL_ADD_BUTTON_CLASS, SMALL_BUTTON_CLASS,
+};
+use super::skills::{Skill, SkillCategory};
+use super::state::AppState;
+
+/// The skill category LinkedIn skills are merged into.
+const SKILLS_CATEGORY: &str = "Skills";
+
+/// Sections found in a LinkedIn data export. A section is `None` when its
+/// CSV was missing from the archive, so replacing never wipes it.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct LinkedInImport {
+    pub basic: Option<Basic>,
+    pub summary: Option<String>,
+    pub experiences: Option<Vec<Experience>>,
+    pub educations: Option<Vec<Education>>,
+    pub skills: Option<Vec<Skill>>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum ImportMode {
+    /// Add what is missing and keep everything already written.
+    #[default]
+    Merge,
+    /// Overwrite each section found in the archive.
+    Replace,
+}
+
+type Row = HashMap<String, String>;
+
+/// Reads CSV rows keyed by header. LinkedIn prefixes some files with notes,
+/// so the header is the first record containing `key_column`.
+fn read_rows(text: &str, key_column: &str) -> Vec<Row> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(text.trim_start_matches('\u{feff}').as_bytes());
+    let mut headers: Option<Vec<String>> = None;
+    let mut rows = vec![];
+    for record in reader.records().flatten() {
+        match &headers {
+            None => {
+                if record.iter().any(|field| field.trim() == key_column) {
+                    headers = Some(
+                        record
+                            .iter()
+                            .map(|field| field.trim().to_string())
+                            .collect(),
+                    );
+                }
+            }
+            Some(headers) => rows.push(
+                headers
+                    .iter()
+                    .cloned()
+                    .zip(record.iter().map(|field| field.trim().to_string()))
+                    .collect(),
+            ),
+        }
+    }
+    rows
+}
+
+fn field<'a>(row: &'a Row, name: &str) -> &'a str {
+    row.get(name).map(String::as_str).unwrap_or_default()
+}
+
+fn same_name(a: &str, b: &str) -> bool {
+    a.trim().eq_ignore_ascii_case(b.trim())
+}
+
+/// Positions are listed newest first; consecutive rows at the same company
+/// become roles of one experience.
+fn parse_positions(rows: &[Row]) -> Vec<Experience> {
+    let mut experiences: Vec<Experience> = vec![];
+    for row in rows {
+        let company = field(row, "Company Name");
+        let started = field(row, "Started On");
+        let finished = field(row, "Finished On");
+        let dates = if finished.is_empty() && !started.is_empty() {
+            DateRange::parse(&format!("{} - Present", started))
+        } else {
+            DateRange::from_parts(started, finished)
+        };
+        let role = RolePeriod {
+            title: field(row, "Title").to_string(),
+            dates,
+            location: field(row, "Location").to_string(),
+            bullets: bullets_from_lines(field(row, "Description")),
+        };
+        match experiences.last_mut() {
+            Some(last) if same_name(&last.employer, company) => last.roles.push(role),
+            _ => experiences.push(Experience {
+                employer: company.to_string(),
+                roles: vec![role],
+                ..Experience::default()
+            }),
+        }
+    }
+    experiences
+}
+
+fn parse_education(rows: &[Row]) -> Vec<Education> {
+    rows.iter()
+        .map(|row| Education {
+            school: field(row, "School Name").to_string(),
+            degree: field(row, "Degree Name").to_string(),
+            dates: DateRange::from_parts(field(row, "Start Date"), field(row, "End Date")),
+            bullets: bullets_from_lines(field(row, "Notes")),
+            ..Education::default()
+        })
+        .collect()
+}
+
+/// Pulls the URLs out of LinkedIn's `[PERSONAL:https://…,COMPANY:https://…]`.
+fn parse_websites(websites: &str) -> Vec<ContactLink> {
+    websites
+        .split(|c: char| c == ',' || c == '[' || c == ']' || c.is_whitespace())
+        .filter_map(|entry| entry.find("http").map(|start| &entry[start..]))
+        .map(|url| ContactLink::new(LinkKind::Website, url))
+        .collect()
+}
+
+fn parse_profile(profile: &[Row], emails: &[Row], phones: &[Row]) -> (Basic, String) {
+    let mut basic = Basic::default();
+    let mut summary = String::new();
+    if let Some(row) = profile.first() {
+        basic.name = [field(row, "First Name"), field(row, "Last Name")]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        basic.headline = field(row, "Headline").to_string();
+        basic.location = field(row, "Geo Location").to_string();
+        basic.links = parse_websites(field(row, "Websites"));
+        summary = field(row, "Summary").to_string();
+    }
+    let primary_email = emails
+        .iter()
+        .find(|row| field(row, "Primary") == "Yes")
+        .or(emails.first());
+    if let Some(row) = primary_email {
+        basic.email = field(row, "Email Address").to_string();
+    }
+    if let Some(row) = phones.first() {
+        basic.phone = field(row, "Number").to_string();
+    }
+    (basic, summary)
+}
+
+/// Maps the CSVs of a LinkedIn data export, given as `(file name, contents)`.
+pub fn parse_linkedin_files(files: &[(String, String)]) -> Result<LinkedInImport, String> {
+    let find = |file_name: &str, key_column: &str| {
+        files
+            .iter()
+            .find(|(name, _)| {
+                let name = name.to_ascii_lowercase();
+                name.rsplit('/').next() == Some(file_name)
+            })
+            .map(|(_, contents)| read_rows(contents, key_column))
+    };
+    let positions = find("positions.csv", "Company Name");
+    let education = find("education.csv", "School Name");
+    let skills = find("skills.csv", "Name");
+    let profile = find("profile.csv", "First Name");
+    if positions.is_none() && education.is_none() && skills.is_none() && profile.is_none() {
+        return Err("No Positions, Education, Skills or Profile CSV in that archive".to_string());
+    }
+
+    let (basic, summary) = match &profile {
+        Some(rows) => {
+            let emails = find("email addresses.csv", "Email Address").unwrap_or_default();
+            let phones = find("phonenumbers.csv", "Number").unwrap_or_default();
+            let (basic, summary) = parse_profile(rows, &emails, &phones);
+            (Some(basic), Some(summary))
+        }
+        None => (None, None),
+    };
+    Ok(LinkedInImport {
+        basic,
+        summary,
+        experiences: positions.as_deref().map(parse_positions),
+        educations: education.as_deref().map(parse_education),
+        skills: skills.map(|rows| {
+            rows.iter()
+                .map(|row| field(row, "Name"))
+                .filter(|name| !name.is_empty())
+                .map(Skill::named)
+                .collect()
+        }),
+    })
+}
+
+/// Reads a LinkedIn data export ZIP in memory.
+pub fn parse_linkedin_archive(bytes: &[u8]) -> Result<LinkedInImport, String> {
+    let mut archive =
+        zip::ZipArchive::new(Cursor::new(bytes)).map_err(|_| "Not a ZIP archive".to_string())?;
+    let mut files = vec![];
+    for idx in 0..archive.len() {
+        let Ok(mut file) = archive.by_index(idx) else {
+            continue;
+        };
+        if !file.name().to_ascii_lowercase().ends_with(".csv") {
+            continue;
+        }
+        let name = file.name().to_string();
+        let mut contents = String::new();
+        if file.read_to_string(&mut contents).is_ok() {
+            files.push((name, contents));
+        }
+    }
+    parse_linkedin_files(&files)
+}
+
+fn fill_if_empty(target: &mut String, value: &str) {
+    if target.trim().is_empty() {
+        *target = value.to_string();
+    }
+}
+
+fn replace_if_given(target: &mut String, value: &str) {
+    if !value.trim().is_empty() {
+        *target = value.to_string();
+    }
+}
+
+fn merge_experiences(existing: &mut Vec<Experience>, imported: &[Experience]) {
+    for experience in imported {
+        let Some(known) = existing
+            .iter_mut()
+            .find(|known| same_name(&known.employer, &experience.employer))
+        else {
+            existing.push(experience.clone());
+            continue;
+        };
+        for role in &experience.roles {
+            let listed = known
+                .roles
+                .iter()
+                .any(|known| same_name(&known.title, &role.title) && known.dates == role.dates);
+            if !listed {
+                known.roles.push(role.clone());
+            }
+        }
+    }
+}
+
+fn merge_skills(existing: &mut Vec<SkillCategory>, imported: &[Skill]) {
+    let fresh: Vec<Skill> = imported
+        .iter()
+        .filter(|skill| {
+            !existing
+                .iter()
+                .flat_map(|category| &category.skills)
+                .any(|known| same_name(&known.name, &skill.name))
+        })
+        .cloned()
+        .collect();
+    if fresh.is_empty() {
+        return;
+    }
+    match existing
+        .iter_mut()
+        .find(|category| same_name(&category.category, SKILLS_CATEGORY))
+    {
+        Some(category) => category.skills.extend(fresh),
+        None => existing.push(SkillCategory {
+            category: SKILLS_CATEGORY.to_string(),
+            skills: fresh,
+            ..SkillCategory::default()
+        }),
+    }
+}
+
+/// The resume after importing; sections missing from the archive are kept.
+pub fn apply_import(state: &AppState, import: &LinkedInImport, mode: ImportMode) -> AppState {
+    let mut next = state.clone();
+    match mode {
+        ImportMode::Merge => {
+            if let Some(basic) = &import.basic {
+                fill_if_empty(&mut next.basic.name, &basic.name);
+                fill_if_empty(&mut next.basic.headline, &basic.headline);
+                fill_if_empty(&mut next.basic.location, &basic.location);
+                fill_if_empty(&mut next.basic.email, &basic.email);
+                fill_if_empty(&mut next.basic.phone, &basic.phone);
+                for link in &basic.links {
+                    if !next.basic.links.iter().any(|known| known.url == link.url) {
+                        next.basic.links.push(link.clone());
+                    }
+                }
+            }
+            if let Some(summary) = &import.summary {
+                fill_if_empty(&mut next.summary, summary);
+            }
+            if let Some(experiences) = &import.experiences {
+                merge_experiences(&mut next.experiences, experiences);
+            }
+            if let Some(educations) = &import.educations {
+                for education in educations {
+                    let listed = next.educations.iter().any(|known| {
+                        same_name(&known.school, &education.school)
+                            && same_name(&known.degree, &education.degree)
+                    });
+                    if !listed {
+                        next.educations.push(education.clone());
+                    }
+                }
+            }
+            if let Some(skills) = &import.skills {
+                merge_skills(&mut next.skills, skills);
+            }
+        }
+        ImportMode::Replace => {
+            if let Some(basic) = &import.basic {
+                replace_if_given(&mut next.basic.name, &basic.name);
+                replace_if_given(&mut next.basic.headline, &basic.headline);
+                replace_if_given(&mut next.basic.location, &basic.location);
+                replace_if_given(&mut next.basic.email, &basic.email);
+                replace_if_given(&mut next.basic.phone, &basic.phone);
+                if !basic.links.is_empty() {
+                    next.basic.links = basic.links.clone();
+                }
+            }
+            if let Some(summary) = &import.summary {
+                replace_if_given(&mut next.summary, summary);
+            }
+            if let Some(experiences) = &import.experiences {
+                next.experiences = experiences.clone();
+            }
+            if let Some(educations) = &import.educations {
+                next.educations = educations.clone();
+            }
+            if let Some(skills) = &import.skills {
+                next.skills.clear();
+                merge_skills(&mut next.skills, skills);
+            }
+        }
+    }
+    next
+}
+
+fn role_count(experiences: &[Experience]) -> usize {
+    experiences
+        .iter()
+        .map(|experience| experience.roles.len())
+        .sum()
+}
+
+fn skill_count(skills: &[SkillCategory]) -> usize {
+    skills.iter().map(|category| category.skills.len()).sum()
+}
+
+/// One line per section that would change, e.g. "Experience: 3 → 5 employers".
+pub fn preview_changes(before: &AppState, after: &AppState) -> Vec<String> {
+    let mut lines = vec![];
+    let mut count = |label: &str, unit: &str, from: usize, to: usize| {
+        if from != to {
+            lines.push(format!("{}: {} → {} {}", label, from, to, unit));
+        }
+    };
+    count(
+        "Experience",
+        "employers",
+        before.experiences.len(),
+        after.experiences.len(),
+    );
+    count(
+        "Experience",
+        "roles",
+        role_count(&before.experiences),
+        role_count(&after.experiences),
+    );
+    count(
+        "Education",
+        "entries",
+        before.educations.len(),
+        after.educations.len(),
+    );
+    count(
+        "Skills",
+        "skills",
+        skill_count(&before.skills),
+        skill_count(&after.skills),
+    );
+    if before.basic != after.basic {
+        lines.push("Basic information updated".to_string());
+    }
+    if before.summary != after.summary {
+        lines.push("Summary updated".to_string());
+    }
+    if lines.is_empty() {
+        lines.push("Nothing new to import".to_string());
+    }
+    lines
+}
+
+#[derive(Properties, PartialEq)]
+pub struct LinkedInImportProps {
+    pub state: AppState,
+    pub on_import: Callback<AppState>,
+}
+
+/// Imports a LinkedIn data export after previewing the result.
+#[function_component(LinkedInImporter)]
+pub fn linkedin_importer(props: &LinkedInImportProps) -> Html {
+    let import = use_state(|| None::<LinkedInImport>);
+    let mode = use_state(ImportMode::default);
+    let error = use_state(String::new);
+
+    let on_load = {
+        let import = import.clone();
+        let error = error.clone();
+        Callback::from(move |file: Result<LoadedFile, String>| {
+            match file.and_then(|file| parse_linkedin_archive(&file.bytes)) {
+                Ok(found) => {
+                    error.set(String::new());
+                    import.set(Some(found));
+                }
+                Err(message) => {
+                    error.set(message);
+                    import.set(None);
+                }
+            }
+        })
+    };
+
+    let preview = import.as_ref().map(|found| {
+        let after = apply_import(&props.state, found, *mode);
+        let lines = preview_changes(&props.state, &after)
+            .into_iter()
+            .map(|line| html! { <li> {line} </li> })
+            .collect::<Html>();
+        let mode_option = |option: ImportMode, label: &'static str| {
+            let mode = mode.clone();
+            html! {
+                <label class="flex items-center gap-x-1">
+                    <input type="radio"
+                           name="linkedin-import-mode"
+                           checked={*mode == option}
+                           onclick={Callback::from(move |_| mode.set(option))}
+                    />
+                    {label}
+                </label>
+            }
+        };
+        let apply = {
+            let import = import.clone();
+            let on_import = props.on_import.clone();
+            Callback::from(move |_| {
+                on_import.emit(after.clone());
+                import.set(None);
+            })
+        };
+        let cancel = {
+            let import = import.clone();
+            Callback::from(move |_| import.set(None))
+        };
+        html! {
+            <>
+                <div class="flex gap-x-3 px-1 py-1 text-xs text-slate-300">
+                    {mode_option(ImportMode::Merge, "Merge")}
+                    {mode_option(ImportMode::Replace, "Replace")}
+                </div>
+                <ul class="list-disc pl-5 text-xs text-slate-300">
+                    {lines}
+                </ul>
+                <div class="flex justify-end gap-x-2">
+                    <button class={SMALL_BUTTON_CLASS} onclick={cancel}> {"Cancel"} </button>
+                    <button class={SMALL_ADD_BUTTON_CLASS} onclick={apply}> {"Import"} </button>
+                </div>
+            </>
+        }
+    });
+
+    html! {
+        <>
+            <div class={INPUT_SECTION_ROW_CLASS}>
+                <h5 class={INPUT_SECTION_CLASS}> {"Import"} </h5>
+            </div>
+            <div class={INPUT_DIVIDER_CLASS}></div>
+            <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                <FilePicker
+                    label="LinkedIn Data Export (.zip)"
+                    accept=".zip,application/zip"
+                    on_load={on_load}
+                />
+                if !error.is_empty() {
+                    <p class="px-1 pt-1 text-xs text-rose-400"> {(*error).clone()} </p>
+                }
+                {preview.unwrap_or_default()}
+            </div>
+        </>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::dates::{DateEnd, PartialDate};
+    use crate::pages::state::default_seed_state;
+
+    fn files() -> Vec<(String, String)> {
+        vec![
+            (
+                "Positions.csv".to_string(),
+                "Company Name,Title,Description,Location,Started On,Finished On\n\
+                 Acme,Staff Engineer,\"Led the platform team\nCut build times in half\",Berlin,Mar 2022,\n\
+                 acme,Senior Engineer,,Berlin,Jan 2020,Feb 2022\n\
+                 Initech,Engineer,Wrote reports,,Jun 2017,Dec 2019\n"
+                    .to_string(),
+            ),
+            (
+                "Basic_LinkedInDataExport/Skills.csv".to_string(),
+                "Name\nRust\nKubernetes\n".to_string(),
+            ),
+            (
+                "Profile.csv".to_string(),
+                "First Name,Last Name,Maiden Name,Headline,Summary,Geo Location,Websites\n\
+                 Ada,Lovelace,,Engineer,Builds things,Berlin,\"[PERSONAL:https://ada.dev]\"\n"
+                    .to_string(),
+            ),
+        ]
+    }
+
+    #[test]
+    fn groups_consecutive_positions_into_roles() {
+        let import = parse_linkedin_files(&files()).expect("archive has CSVs");
+        let experiences = import.experiences.expect("positions were present");
+        assert_eq!(experiences.len(), 2);
+        assert_eq!(experiences[0].employer, "Acme");
+        assert_eq!(experiences[0].roles.len(), 2);
+        assert_eq!(experiences[0].roles[0].bullets.len(), 2);
+        assert_eq!(
+            experiences[0].roles[0].dates.end,
+            Some(DateEnd::Present),
+            "an empty end date means the position is current"
+        );
+        assert_eq!(
+            experiences[0].roles[1].dates.start,
+            Some(PartialDate {
+                year: 2020,
+                month: 1,
+                day: None
+            })
+        );
+        assert_eq!(import.educations, None);
+
+        let basic = import.basic.expect("profile was present");
+        assert_eq!(basic.name, "Ada Lovelace");
+        assert_eq!(basic.links[0].url, "https://ada.dev");
+    }
+
+    #[test]
+    fn merge_keeps_existing_entries_and_replace_overwrites() {
+        let import = parse_linkedin_files(&files()).expect("archive has CSVs");
+        let mut state = default_seed_state();
+        state.summary = String::new();
+        let state = AppState {
+            experiences: vec![Experience {
+                employer: "ACME".to_string(),
+                roles: vec![RolePeriod {
+                    title: "Staff Engineer".to_string(),
+                    dates: DateRange::parse("Mar 2022 - Present"),
+                    ..RolePeriod::default()
+                }],
+                ..Experience::default()
+            }],
+            skills: vec![SkillCategory {
+                category: "Languages".to_string(),
+                skills: vec![Skill::named("rust")],
+                ..SkillCategory::default()
+            }],
+            ..state
+        };
+
+        let merged = apply_import(&state, &import, ImportMode::Merge);
+        assert_eq!(merged.experiences.len(), 2);
+        assert_eq!(merged.experiences[0].employer, "ACME");
+        assert_eq!(merged.experiences[0].roles.len(), 2);
+        assert_eq!(skill_count(&merged.skills), 2);
+
+        let replaced = apply_import(&state, &import, ImportMode::Replace);
+        assert_eq!(replaced.experiences[0].employer, "Acme");
+        assert_eq!(replaced.skills.len(), 1);
+        assert_eq!(skill_count(&replaced.skills), 2);
+        assert!(preview_changes(&state, &replaced).contains(&"Summary updated".to_string()));
+    }
+
+    #[test]
+    fn reads_csvs_from_a_zip() {
+        use std::io::Write;
+
+        let mut archive = zip::ZipWriter::new(Cursor::new(vec![]));
+        for (name, contents) in files() {
+            archive
+                .start_file(name, zip::write::FileOptions::default())
+                .expect("start file");
+            archive.write_all(contents.as_bytes()).expect("write file");
+        }
+        let bytes = archive.finish().expect("finish archive").into_inner();
+
+        let import = parse_linkedin_archive(&bytes).expect("valid archive");
+        assert_eq!(import.skills.map(|skills| skills.len()), Some(2));
+        assert!(parse_linkedin_archive(b"not a zip").is_err());
+    }
+}
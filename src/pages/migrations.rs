@@ -0,0 +1,438 @@
+use serde_json::{json, Value};
+
+use super::bullets::bullets_from_lines;
+use super::dates::DateRange;
+use super::open_source::parse_contribution_url;
+use super::skills::skills_from_list;
+use super::state::STATE_VERSION;
+use super::tech_stack::utilized_list;
+use super::variants::parse_tags;
+
+type Migration = fn(&mut Value) -> Result<(), String>;
+
+/// Each entry upgrades a raw state from version `index + 1` to `index + 2`.
+const MIGRATIONS: &[Migration] = &[
+    v1_to_v2, v2_to_v3, v3_to_v4, v4_to_v5, v5_to_v6, v6_to_v7, v7_to_v8, v8_to_v9,
+];
+
+/// Upgrades a raw, possibly older, state to `STATE_VERSION`. States without a
+/// version predate versioning and are treated as version 1.
+pub fn migrate(mut state: Value) -> Result<Value, String> {
+    let version = match state.get("v") {
+        None => 1,
+        Some(v) => v
+            .as_u64()
+            .ok_or_else(|| format!("invalid state version: {}", v))?,
+    };
+    if version == 0 || version > STATE_VERSION as u64 {
+        return Err(format!(
+            "unsupported state version: {}, expected {}",
+            version, STATE_VERSION
+        ));
+    }
+    for migration in &MIGRATIONS[version as usize - 1..] {
+        migration(&mut state)?;
+    }
+    state["v"] = Value::from(STATE_VERSION);
+    Ok(state)
+}
+
+fn objects_mut<'a>(state: &'a mut Value, key: &str) -> impl Iterator<Item = &'a mut Value> {
+    state
+        .get_mut(key)
+        .and_then(Value::as_array_mut)
+        .into_iter()
+        .flatten()
+        .filter(|item| item.is_object())
+}
+
+fn take_string(object: &mut Value, key: &str) -> String {
+    match object.as_object_mut().and_then(|o| o.remove(key)) {
+        Some(Value::String(value)) => value,
+        _ => String::new(),
+    }
+}
+
+/// Experience descriptions became a list of bullets.
+fn v1_to_v2(state: &mut Value) -> Result<(), String> {
+    for experience in objects_mut(state, "experiences") {
+        let description = experience
+            .as_object_mut()
+            .and_then(|e| e.remove("description"));
+        let bullets = match description {
+            Some(Value::String(description)) => bullets_from_lines(&description),
+            _ => vec![],
+        };
+        experience["bullets"] = serde_json::to_value(bullets).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Experience-level `title`, `dates` and `location` moved into `roles`.
+fn v2_to_v3(state: &mut Value) -> Result<(), String> {
+    for experience in objects_mut(state, "experiences") {
+        let title = take_string(experience, "title");
+        let dates = take_string(experience, "dates");
+        let location = take_string(experience, "location");
+        let Some(experience) = experience.as_object_mut() else {
+            continue;
+        };
+
+        let roles = experience
+            .entry("roles")
+            .or_insert_with(|| Value::Array(vec![]));
+        let Some(roles) = roles.as_array_mut() else {
+            return Err("experience roles must be a list".to_string());
+        };
+        if roles.is_empty() {
+            roles.push(json!({ "title": title, "dates": dates, "location": location }));
+            continue;
+        }
+        for role in roles.iter_mut().filter(|role| role.is_object()) {
+            let role_location = role.get("location").and_then(Value::as_str);
+            if role_location.is_none_or(str::is_empty) {
+                role["location"] = Value::from(location.clone());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Free-text dates became typed date ranges.
+fn v3_to_v4(state: &mut Value) -> Result<(), String> {
+    let to_value = |range: DateRange| serde_json::to_value(range).map_err(|e| e.to_string());
+    for experience in objects_mut(state, "experiences") {
+        for role in objects_mut(experience, "roles") {
+            let dates = take_string(role, "dates");
+            role["dates"] = to_value(DateRange::parse(&dates))?;
+        }
+    }
+    for education in objects_mut(state, "educations") {
+        let start = take_string(education, "start_date");
+        let end = take_string(education, "end_date");
+        education["dates"] = to_value(DateRange::from_parts(&start, &end))?;
+    }
+    Ok(())
+}
+
+/// Comma-separated skill strings became lists of skill entries.
+fn v4_to_v5(state: &mut Value) -> Result<(), String> {
+    for category in objects_mut(state, "skills") {
+        let skills = take_string(category, "skills");
+        category["skills"] =
+            serde_json::to_value(skills_from_list(&skills)).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Removes `**Utilized**: a, b` bullets from a bullet list, returning the
+/// technologies they listed. Hidden or tagged bullets stay as they are, since
+/// a tech stack is shown in every variant.
+fn take_utilized(bullets: Option<&mut Value>) -> Vec<String> {
+    let Some(bullets) = bullets.and_then(Value::as_array_mut) else {
+        return vec![];
+    };
+    let mut tech_stack = vec![];
+    bullets.retain(|bullet| {
+        let hidden = bullet["hidden"].as_bool().unwrap_or(false);
+        let tagged = bullet["tags"]
+            .as_array()
+            .is_some_and(|tags| !tags.is_empty());
+        if hidden || tagged {
+            return true;
+        }
+        match bullet
+            .get("text")
+            .and_then(Value::as_str)
+            .and_then(utilized_list)
+        {
+            Some(list) => {
+                tech_stack.extend(parse_tags(list));
+                false
+            }
+            None => true,
+        }
+    });
+    tech_stack
+}
+
+/// "**Utilized**" bullets and project technologies became tech stacks.
+fn v5_to_v6(state: &mut Value) -> Result<(), String> {
+    for experience in objects_mut(state, "experiences") {
+        let mut tech_stack = take_utilized(experience.get_mut("bullets"));
+        for role in objects_mut(experience, "roles") {
+            tech_stack.extend(take_utilized(role.get_mut("bullets")));
+        }
+        experience["tech_stack"] = Value::from(tech_stack);
+    }
+    for project in objects_mut(state, "projects") {
+        let technologies = take_string(project, "technologies");
+        project["tech_stack"] = Value::from(parse_tags(&technologies));
+    }
+    Ok(())
+}
+
+/// A project's single `url` became a list of labelled links.
+fn v6_to_v7(state: &mut Value) -> Result<(), String> {
+    for project in objects_mut(state, "projects") {
+        let url = take_string(project, "url");
+        project["links"] = match url.trim() {
+            "" => json!([]),
+            url => json!([{ "label": "", "url": url }]),
+        };
+    }
+    Ok(())
+}
+
+/// The fixed LinkedIn and GitHub fields became an ordered list of links.
+fn v7_to_v8(state: &mut Value) -> Result<(), String> {
+    let Some(basic) = state.get_mut("basic").filter(|basic| basic.is_object()) else {
+        return Ok(());
+    };
+    let links = [("LinkedIn", "linkedin_url"), ("GitHub", "github_url")]
+        .into_iter()
+        .map(|(kind, key)| (kind, take_string(basic, key)))
+        .filter(|(_, url)| !url.trim().is_empty())
+        .map(|(kind, url)| json!({ "kind": kind, "url": url }))
+        .collect::<Vec<_>>();
+    basic["links"] = Value::Array(links);
+    Ok(())
+}
+
+/// Open source entries gained fields parsed from their pull request or
+/// issue URL.
+fn v8_to_v9(state: &mut Value) -> Result<(), String> {
+    for open_source in objects_mut(state, "open_sources") {
+        let url = open_source["url"].as_str().unwrap_or_default();
+        if let Some(parsed) = parse_contribution_url(url) {
+            let kind = serde_json::to_value(parsed.kind).map_err(|e| e.to_string())?;
+            open_source["owner"] = Value::from(parsed.owner);
+            open_source["repo"] = Value::from(parsed.repo);
+            open_source["number"] = Value::from(parsed.number);
+            open_source["kind"] = kind;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn v1_descriptions_become_bullets() {
+        let state = json!({
+            "v": 1,
+            "experiences": [{
+                "employer": "DRW",
+                "team": null,
+                "location": "Chicago, IL",
+                "description": "Built things {backend}\n\n**Utilized**: Rust",
+            }],
+        });
+
+        let migrated = migrate(state).expect("v1 state should migrate");
+        assert_eq!(migrated["v"], json!(STATE_VERSION));
+        let bullets = &migrated["experiences"][0]["bullets"];
+        assert_eq!(bullets.as_array().map(Vec::len), Some(1));
+        assert_eq!(bullets[0]["text"], json!("Built things"));
+        assert_eq!(bullets[0]["tags"], json!(["backend"]));
+        assert_eq!(migrated["experiences"][0]["tech_stack"], json!(["Rust"]));
+    }
+
+    #[test]
+    fn v2_legacy_experience_fields_move_into_roles() {
+        let state = json!({
+            "v": 2,
+            "experiences": [
+                {
+                    "employer": "Uber",
+                    "title": "Intern",
+                    "dates": "2020/05 - 2020/07",
+                    "location": "Atlanta, GA",
+                    "bullets": [],
+                },
+                {
+                    "employer": "DRW",
+                    "title": "Engineer",
+                    "dates": "2022/07 - 2025/01",
+                    "location": "Chicago, IL",
+                    "roles": [
+                        { "title": "Engineer", "dates": "2022/07 - 2025/01", "location": "" },
+                        { "title": "Intern", "dates": "2021/06 - 2021/08", "location": "Remote" },
+                    ],
+                },
+            ],
+        });
+
+        let migrated = migrate(state).expect("v2 state should migrate");
+        let uber = &migrated["experiences"][0];
+        assert_eq!(uber["roles"].as_array().map(Vec::len), Some(1));
+        assert_eq!(uber["roles"][0]["title"], json!("Intern"));
+        assert_eq!(uber["roles"][0]["location"], json!("Atlanta, GA"));
+        assert_eq!(uber["roles"][0]["dates"]["start"]["year"], json!(2020));
+        assert!(uber.get("title").is_none());
+        let drw = &migrated["experiences"][1];
+        assert_eq!(drw["roles"][0]["location"], json!("Chicago, IL"));
+        assert_eq!(drw["roles"][1]["location"], json!("Remote"));
+        assert!(drw.get("location").is_none());
+    }
+
+    #[test]
+    fn v3_date_strings_become_ranges() {
+        let state = json!({
+            "v": 3,
+            "experiences": [{
+                "employer": "DRW",
+                "roles": [{ "title": "Head", "dates": "2025/01 - Present", "location": "" }],
+            }],
+            "educations": [
+                { "school": "GT", "start_date": "2021/08", "end_date": "2022/05" },
+                { "school": "GT", "start_date": "Fall '17", "end_date": "2021/05" },
+            ],
+        });
+
+        let migrated = migrate(state).expect("v3 state should migrate");
+        assert_eq!(
+            migrated["experiences"][0]["roles"][0]["dates"],
+            json!({ "start": { "year": 2025, "month": 1 }, "end": "Present" })
+        );
+        assert_eq!(
+            migrated["educations"][0]["dates"]["end"],
+            json!({ "On": { "year": 2022, "month": 5 } })
+        );
+        assert_eq!(
+            migrated["educations"][1]["dates"]["text"],
+            json!("Fall '17 - 2021/05")
+        );
+        assert!(migrated["educations"][1].get("start_date").is_none());
+    }
+
+    #[test]
+    fn v4_skill_strings_become_entries() {
+        let state = json!({
+            "v": 4,
+            "skills": [{ "category": "Languages", "skills": "Rust, Python , Go" }],
+        });
+
+        let migrated = migrate(state).expect("v4 state should migrate");
+        assert_eq!(
+            migrated["skills"][0]["skills"],
+            json!([{ "name": "Rust" }, { "name": "Python" }, { "name": "Go" }])
+        );
+    }
+
+    #[test]
+    fn v5_utilized_bullets_become_tech_stacks() {
+        let state = json!({
+            "v": 5,
+            "experiences": [{
+                "employer": "Uber",
+                "bullets": [{ "text": "Built things" }, { "text": "**Utilized**: Python, PostgreSQL" }],
+                "roles": [{ "title": "Intern", "bullets": [{ "text": "**Utilized**: ROS" }] }],
+            }],
+            "projects": [{ "name": "Rusume", "description": "", "technologies": "Rust, Yew" }],
+        });
+
+        let migrated = migrate(state).expect("v5 state should migrate");
+        let uber = &migrated["experiences"][0];
+        assert_eq!(uber["tech_stack"], json!(["Python", "PostgreSQL", "ROS"]));
+        assert_eq!(uber["bullets"], json!([{ "text": "Built things" }]));
+        assert_eq!(uber["roles"][0]["bullets"], json!([]));
+        assert_eq!(
+            migrated["projects"][0]["tech_stack"],
+            json!(["Rust", "Yew"])
+        );
+        assert!(migrated["projects"][0].get("technologies").is_none());
+    }
+
+    #[test]
+    fn v5_hidden_or_tagged_utilized_bullets_stay_bullets() {
+        let hidden = json!({ "text": "**Utilized**: Perl", "hidden": true });
+        let tagged = json!({ "text": "**Utilized**: ROS", "tags": ["robotics"] });
+        let state = json!({
+            "v": 5,
+            "experiences": [{
+                "employer": "Uber",
+                "bullets": [hidden, { "text": "**Utilized**: Python" }],
+                "roles": [{ "title": "Intern", "bullets": [tagged] }],
+            }],
+        });
+
+        let migrated = migrate(state).expect("v5 state should migrate");
+        let uber = &migrated["experiences"][0];
+        assert_eq!(uber["tech_stack"], json!(["Python"]));
+        assert_eq!(uber["bullets"], json!([hidden]));
+        assert_eq!(uber["roles"][0]["bullets"], json!([tagged]));
+    }
+
+    #[test]
+    fn v6_project_urls_become_links() {
+        let state = json!({
+            "v": 6,
+            "projects": [
+                { "name": "Rusume", "description": "", "url": "https://example.com" },
+                { "name": "Iterr", "description": "", "url": null },
+            ],
+        });
+
+        let migrated = migrate(state).expect("v6 state should migrate");
+        assert_eq!(
+            migrated["projects"][0]["links"],
+            json!([{ "label": "", "url": "https://example.com" }])
+        );
+        assert_eq!(migrated["projects"][1]["links"], json!([]));
+        assert!(migrated["projects"][0].get("url").is_none());
+    }
+
+    #[test]
+    fn v7_profile_urls_become_links() {
+        let state = json!({
+            "v": 7,
+            "basic": {
+                "name": "Allen",
+                "email": "",
+                "phone": "",
+                "linkedin_url": "https://linkedin.com/in/allen",
+                "github_url": null,
+            },
+        });
+
+        let migrated = migrate(state).expect("v7 state should migrate");
+        assert_eq!(
+            migrated["basic"]["links"],
+            json!([{ "kind": "LinkedIn", "url": "https://linkedin.com/in/allen" }])
+        );
+        assert!(migrated["basic"].get("linkedin_url").is_none());
+        assert!(migrated["basic"].get("github_url").is_none());
+    }
+
+    #[test]
+    fn v8_open_source_urls_are_parsed() {
+        let state = json!({
+            "v": 8,
+            "open_sources": [
+                { "name": "kafka-rust #222", "url": "https://github.com/kafka-rust/kafka-rust/pull/222" },
+                { "name": "Blog post", "url": "https://example.com" },
+            ],
+        });
+
+        let migrated = migrate(state).expect("v8 state should migrate");
+        let kafka = &migrated["open_sources"][0];
+        assert_eq!(kafka["owner"], json!("kafka-rust"));
+        assert_eq!(kafka["number"], json!(222));
+        assert_eq!(kafka["kind"], json!("PullRequest"));
+        assert!(migrated["open_sources"][1].get("repo").is_none());
+    }
+
+    #[test]
+    fn unversioned_state_is_treated_as_v1() {
+        let migrated = migrate(json!({ "experiences": [] })).expect("should migrate");
+        assert_eq!(migrated["v"], json!(STATE_VERSION));
+    }
+
+    #[test]
+    fn future_versions_are_rejected() {
+        assert!(migrate(json!({ "v": STATE_VERSION as u64 + 1 })).is_err());
+    }
+}
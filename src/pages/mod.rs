@@ -4,11 +4,24 @@ use yew::prelude::*;
 
 mod html_utils;
 
+mod bullets;
+
 mod basic;
 use basic::Basic;
 use basic::BasicController;
 use basic::BasicViewer;
 
+mod certifications;
+use certifications::hide_expired_certifications;
+use certifications::Certification;
+use certifications::CertificationController;
+use certifications::CertificationViewer;
+
+mod dates;
+use dates::current_month;
+use dates::DateFormat;
+use dates::DateFormatController;
+
 mod education;
 use education::Education;
 use education::EducationController;
@@ -19,35 +32,125 @@ use experiences::Experience;
 use experiences::ExperienceController;
 use experiences::ExperienceViewer;
 
+mod photo;
+use photo::remember_photo;
+use photo::restore_photo;
+
 mod projects;
 use projects::ProjectController;
 use projects::ProjectViewer;
 
+mod publications;
+use publications::CitationStyle;
+use publications::Publication;
+use publications::PublicationController;
+use publications::PublicationViewer;
+
 mod skills;
 use skills::SkillController;
+use skills::SkillStyle;
 use skills::SkillViewer;
 
+mod export;
+use export::ExportController;
+
+mod file_input;
+
+mod github_import;
+
+mod linkedin_import;
+use linkedin_import::LinkedInImporter;
+
+mod languages;
+use languages::Language;
+use languages::LanguageController;
+use languages::LanguageViewer;
+
 mod open_source;
 use open_source::OpenSourceController;
 use open_source::OpenSourceViewer;
 
+mod references;
+use references::remember_reference_contacts;
+use references::restore_reference_contacts;
+use references::Reference;
+use references::ReferenceController;
+use references::ReferenceSettings;
+use references::ReferenceViewer;
+
+mod sections;
+use sections::Section;
+
+mod sorting;
+use sorting::apply_sorting;
+use sorting::SortController;
+use sorting::SortSettings;
+
+mod summary;
+use summary::SummaryController;
+use summary::SummaryViewer;
+
+mod tech_stack;
+use tech_stack::TechStackPanel;
+
+mod text_import;
+use text_import::TextImporter;
+
+mod themes;
+use themes::Theme;
+use themes::ThemeController;
+
+mod timeline;
+use timeline::TimelinePanel;
+use timeline::TimelineSettings;
+
+mod variants;
+use variants::apply_variant;
+use variants::section_layout;
+use variants::Variant;
+use variants::VariantController;
+
+mod migrations;
+
 mod state;
+use state::assign_storage_ids;
 use state::load_state_from_url;
+use state::load_variant_from_url;
 use state::write_state_to_url;
 use state::AppState;
 
 pub struct Home {
     state: AppState,
+    active_variant: Option<usize>,
 }
 
 pub enum HomeMsg {
     Print,
-    UpdateBasicSection(Basic),
+    ImportState(Box<AppState>),
+    UpdateBasicSection(Box<Basic>),
+    UpdateSummarySection(String),
     UpdateEducationSection(Vec<Education>),
     UpdateExperienceSection(Vec<Experience>),
+    UpdateVolunteeringSection(Vec<Experience>),
     UpdateProjectSection(Vec<projects::Project>),
     UpdateSkillSection(Vec<skills::SkillCategory>),
+    UpdateSkillStyle(SkillStyle),
+    UpdateLanguageSection(Vec<Language>),
     UpdateOpenSourceSection(Vec<open_source::OpenSource>),
+    UpdateGroupOpenSource(bool),
+    UpdateCertificationSection(Vec<Certification>),
+    UpdateHideExpiredCertifications(bool),
+    UpdatePublicationSection(Vec<Publication>),
+    UpdateCitationStyle(CitationStyle),
+    UpdateReferenceSection(Vec<Reference>),
+    UpdateReferenceSettings(ReferenceSettings),
+    UpdateSectionOrder(Vec<Section>),
+    UpdateVariants(Vec<Variant>),
+    SelectVariant(Option<usize>),
+    UpdateDateFormat(DateFormat),
+    UpdateSorting(SortSettings),
+    UpdateTimeline(TimelineSettings),
+    UpdateTheme(Theme),
 }
 
 #[derive(Properties, PartialEq)]
@@ -58,9 +161,17 @@ impl Component for Home {
     type Properties = HomeProps;
 
     fn create(_ctx: &Context<Self>) -> Self {
-        let state = load_state_from_url();
-        write_state_to_url(&state);
-        Self { state }
+        let mut state = load_state_from_url();
+        assign_storage_ids(&mut state);
+        restore_reference_contacts(&mut state.references, &state.reference_settings);
+        restore_photo(&mut state.basic.photo);
+        let active_variant = load_variant_from_url(&state);
+        let home = Self {
+            state,
+            active_variant,
+        };
+        home.sync_url();
+        home
     }
 
     fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
@@ -72,9 +183,22 @@ impl Component for Home {
                     let _ = window.print();
                 }
             }
+            HomeMsg::ImportState(mut state) => {
+                assign_storage_ids(&mut state);
+                if self.state != *state {
+                    self.state = *state;
+                    state_changed = true;
+                }
+            }
             HomeMsg::UpdateBasicSection(basic) => {
-                if self.state.basic != basic {
-                    self.state.basic = basic;
+                if self.state.basic != *basic {
+                    self.state.basic = *basic;
+                    state_changed = true;
+                }
+            }
+            HomeMsg::UpdateSummarySection(summary) => {
+                if self.state.summary != summary {
+                    self.state.summary = summary;
                     state_changed = true;
                 }
             }
@@ -102,28 +226,277 @@ impl Component for Home {
                     state_changed = true;
                 }
             }
+            HomeMsg::UpdateSkillStyle(skill_style) => {
+                if self.state.skill_style != skill_style {
+                    self.state.skill_style = skill_style;
+                    state_changed = true;
+                }
+            }
+            HomeMsg::UpdateVolunteeringSection(volunteering) => {
+                if self.state.volunteering != volunteering {
+                    self.state.volunteering = volunteering;
+                    state_changed = true;
+                }
+            }
+            HomeMsg::UpdateGroupOpenSource(grouped) => {
+                if self.state.group_open_source != grouped {
+                    self.state.group_open_source = grouped;
+                    state_changed = true;
+                }
+            }
+            HomeMsg::UpdateLanguageSection(languages) => {
+                if self.state.languages != languages {
+                    self.state.languages = languages;
+                    state_changed = true;
+                }
+            }
             HomeMsg::UpdateOpenSourceSection(open_sources) => {
                 if self.state.open_sources != open_sources {
                     self.state.open_sources = open_sources;
                     state_changed = true;
                 }
             }
+            HomeMsg::UpdateCertificationSection(certifications) => {
+                if self.state.certifications != certifications {
+                    self.state.certifications = certifications;
+                    state_changed = true;
+                }
+            }
+            HomeMsg::UpdateHideExpiredCertifications(hide_expired) => {
+                if self.state.hide_expired_certifications != hide_expired {
+                    self.state.hide_expired_certifications = hide_expired;
+                    state_changed = true;
+                }
+            }
+            HomeMsg::UpdatePublicationSection(publications) => {
+                if self.state.publications != publications {
+                    self.state.publications = publications;
+                    state_changed = true;
+                }
+            }
+            HomeMsg::UpdateCitationStyle(citation_style) => {
+                if self.state.citation_style != citation_style {
+                    self.state.citation_style = citation_style;
+                    state_changed = true;
+                }
+            }
+            HomeMsg::UpdateReferenceSection(references) => {
+                if self.state.references != references {
+                    self.state.references = references;
+                    state_changed = true;
+                }
+            }
+            HomeMsg::UpdateReferenceSettings(reference_settings) => {
+                if self.state.reference_settings != reference_settings {
+                    self.state.reference_settings = reference_settings;
+                    state_changed = true;
+                }
+            }
+            HomeMsg::UpdateSectionOrder(section_order) => {
+                if self.state.section_order != section_order {
+                    self.state.section_order = section_order;
+                    state_changed = true;
+                }
+            }
+            HomeMsg::UpdateVariants(variants) => {
+                if self.state.variants != variants {
+                    self.state.variants = variants;
+                    if self
+                        .active_variant
+                        .is_some_and(|idx| idx >= self.state.variants.len())
+                    {
+                        self.active_variant = None;
+                    }
+                    state_changed = true;
+                }
+            }
+            HomeMsg::UpdateDateFormat(date_format) => {
+                if self.state.date_format != date_format {
+                    self.state.date_format = date_format;
+                    state_changed = true;
+                }
+            }
+            HomeMsg::UpdateSorting(sorting) => {
+                if self.state.sorting != sorting {
+                    self.state.sorting = sorting;
+                    state_changed = true;
+                }
+            }
+            HomeMsg::UpdateTimeline(timeline) => {
+                if self.state.timeline != timeline {
+                    self.state.timeline = timeline;
+                    state_changed = true;
+                }
+            }
+            HomeMsg::UpdateTheme(theme) => {
+                if self.state.theme != theme {
+                    self.state.theme = theme;
+                    state_changed = true;
+                }
+            }
+            HomeMsg::SelectVariant(active_variant) => {
+                if self.active_variant != active_variant {
+                    self.active_variant = active_variant;
+                    state_changed = true;
+                }
+            }
         }
 
         if state_changed {
-            write_state_to_url(&self.state);
+            self.sync_url();
         }
 
         state_changed
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
+        let import_cb = ctx
+            .link()
+            .callback(|state| HomeMsg::ImportState(Box::new(state)));
         let education_cb = ctx.link().callback(HomeMsg::UpdateEducationSection);
         let experience_cb = ctx.link().callback(HomeMsg::UpdateExperienceSection);
+        let volunteering_cb = ctx.link().callback(HomeMsg::UpdateVolunteeringSection);
         let project_cb = ctx.link().callback(HomeMsg::UpdateProjectSection);
-        let basic_cb = ctx.link().callback(HomeMsg::UpdateBasicSection);
+        let basic_cb = ctx
+            .link()
+            .callback(|basic| HomeMsg::UpdateBasicSection(Box::new(basic)));
+        let summary_cb = ctx.link().callback(HomeMsg::UpdateSummarySection);
         let skill_cb = ctx.link().callback(HomeMsg::UpdateSkillSection);
+        let skill_style_cb = ctx.link().callback(HomeMsg::UpdateSkillStyle);
+        let language_cb = ctx.link().callback(HomeMsg::UpdateLanguageSection);
         let open_source_cb = ctx.link().callback(HomeMsg::UpdateOpenSourceSection);
+        let group_open_source_cb = ctx.link().callback(HomeMsg::UpdateGroupOpenSource);
+        let certification_cb = ctx.link().callback(HomeMsg::UpdateCertificationSection);
+        let hide_expired_cb = ctx
+            .link()
+            .callback(HomeMsg::UpdateHideExpiredCertifications);
+        let publication_cb = ctx.link().callback(HomeMsg::UpdatePublicationSection);
+        let citation_style_cb = ctx.link().callback(HomeMsg::UpdateCitationStyle);
+        let reference_cb = ctx.link().callback(HomeMsg::UpdateReferenceSection);
+        let reference_settings_cb = ctx.link().callback(HomeMsg::UpdateReferenceSettings);
+        let section_order_cb = ctx.link().callback(HomeMsg::UpdateSectionOrder);
+        let variants_cb = ctx.link().callback(HomeMsg::UpdateVariants);
+        let select_variant_cb = ctx.link().callback(HomeMsg::SelectVariant);
+        let date_format_cb = ctx.link().callback(HomeMsg::UpdateDateFormat);
+        let sorting_cb = ctx.link().callback(HomeMsg::UpdateSorting);
+        let timeline_cb = ctx.link().callback(HomeMsg::UpdateTimeline);
+        let theme_cb = ctx.link().callback(HomeMsg::UpdateTheme);
+
+        let variant = self.active_variant();
+        let mut shown = apply_variant(&self.state, variant);
+        apply_sorting(&mut shown);
+        hide_expired_certifications(&mut shown, current_month());
+        let theme = self.state.theme;
+        let sections = section_layout(&self.state, variant)
+            .into_iter()
+            .map(|(section, heading)| {
+                let view = match section {
+                    Section::Summary => html! {
+                        <SummaryViewer
+                            summary={shown.summary.clone()}
+                            heading={heading}
+                            theme={theme}
+                        />
+                    },
+                    Section::Skills => html! {
+                        <SkillViewer
+                            skills={shown.skills.clone()}
+                            heading={heading}
+                            theme={theme}
+                            style={shown.skill_style}
+                        />
+                    },
+                    Section::Languages => html! {
+                        <LanguageViewer
+                            languages={shown.languages.clone()}
+                            heading={heading}
+                            theme={theme}
+                        />
+                    },
+                    Section::Experiences => html! {
+                        <ExperienceViewer
+                            experiences={shown.experiences.clone()}
+                            heading={heading}
+                            theme={theme}
+                            date_format={shown.date_format.clone()}
+                        />
+                    },
+                    Section::Volunteering => html! {
+                        <ExperienceViewer
+                            experiences={shown.volunteering.clone()}
+                            heading={heading}
+                            theme={theme}
+                            date_format={shown.date_format.clone()}
+                        />
+                    },
+                    Section::Projects => html! {
+                        <ProjectViewer
+                            projects={shown.projects.clone()}
+                            heading={heading}
+                            theme={theme}
+                            date_format={shown.date_format.clone()}
+                        />
+                    },
+                    Section::OpenSource => html! {
+                        <OpenSourceViewer
+                            open_sources={shown.open_sources.clone()}
+                            heading={heading}
+                            theme={theme}
+                            grouped={shown.group_open_source}
+                        />
+                    },
+                    Section::Education => html! {
+                        <EducationViewer
+                            educations={shown.educations.clone()}
+                            heading={heading}
+                            theme={theme}
+                            date_format={shown.date_format.clone()}
+                        />
+                    },
+                    Section::Publications => html! {
+                        <PublicationViewer
+                            publications={shown.publications.clone()}
+                            heading={heading}
+                            theme={theme}
+                            style={shown.citation_style}
+                            owner={shown.basic.name.clone()}
+                        />
+                    },
+                    Section::Certifications => html! {
+                        <CertificationViewer
+                            certifications={shown.certifications.clone()}
+                            heading={heading}
+                            theme={theme}
+                            date_format={shown.date_format.clone()}
+                        />
+                    },
+                    Section::References => html! {
+                        <ReferenceViewer
+                            references={shown.references.clone()}
+                            heading={heading}
+                            theme={theme}
+                            mode={shown.reference_settings.mode}
+                        />
+                    },
+                };
+                (section, view)
+            })
+            .collect::<Vec<_>>();
+        // Two-Column moves some sections into a narrow side column.
+        let (side, main): (Vec<_>, Vec<_>) = sections
+            .into_iter()
+            .partition(|(section, _)| theme.in_side_column(*section));
+        let main = main.into_iter().map(|(_, view)| view).collect::<Html>();
+        let sections = if side.is_empty() {
+            main
+        } else {
+            html! {
+                <div class="grid grid-cols-[30%_minmax(0,1fr)] gap-x-6">
+                    <div> {side.into_iter().map(|(_, view)| view).collect::<Html>()} </div>
+                    <div> {main} </div>
+                </div>
+            }
+        };
 
         let print_button = html! {
             <button
@@ -146,24 +519,83 @@ impl Component for Home {
                         {print_button.clone()}
                     </div>
                     <div class="sidebar-scroll">
+                        <VariantController
+                            value={self.state.variants.clone()}
+                            section_order={self.state.section_order.clone()}
+                            active={self.active_variant}
+                            on_change={variants_cb}
+                            on_section_order_change={section_order_cb}
+                            on_select={select_variant_cb}
+                        />
+                        <ExportController state={shown.clone()} />
+                        <LinkedInImporter state={self.state.clone()} on_import={import_cb.clone()} />
+                        <TextImporter state={self.state.clone()} on_import={import_cb} />
+                        <ThemeController value={self.state.theme} on_change={theme_cb} />
+                        <DateFormatController value={self.state.date_format.clone()} on_change={date_format_cb} />
+                        <SortController value={self.state.sorting.clone()} on_change={sorting_cb} />
+                        <TimelinePanel
+                            experiences={self.state.experiences.clone()}
+                            educations={self.state.educations.clone()}
+                            settings={self.state.timeline.clone()}
+                            on_settings_change={timeline_cb}
+                        />
+                        <TechStackPanel
+                            experiences={self.state.experiences.clone()}
+                            projects={self.state.projects.clone()}
+                            skills={self.state.skills.clone()}
+                            on_skills_change={skill_cb.clone()}
+                        />
                         <BasicController value={self.state.basic.clone()} on_change={basic_cb}/>
-                        <SkillController value={self.state.skills.clone()} on_change={skill_cb} />
+                        <SummaryController value={self.state.summary.clone()} on_change={summary_cb} />
+                        <SkillController
+                            value={self.state.skills.clone()}
+                            on_change={skill_cb}
+                            style={self.state.skill_style}
+                            on_style_change={skill_style_cb}
+                        />
+                        <LanguageController value={self.state.languages.clone()} on_change={language_cb} />
                         <EducationController value={self.state.educations.clone()} on_change={education_cb}/>
                         <ExperienceController value={self.state.experiences.clone()} on_change={experience_cb} />
+                        <ExperienceController
+                            value={self.state.volunteering.clone()}
+                            on_change={volunteering_cb}
+                            title="Volunteering & Leadership"
+                            employer_label="Organization"
+                            employment={false}
+                        />
                         <ProjectController value={self.state.projects.clone()} on_change={project_cb} />
-                        <OpenSourceController value={self.state.open_sources.clone()} on_change={open_source_cb} />
+                        <OpenSourceController
+                            value={self.state.open_sources.clone()}
+                            on_change={open_source_cb}
+                            grouped={self.state.group_open_source}
+                            on_grouped_change={group_open_source_cb}
+                        />
+                        <PublicationController
+                            value={self.state.publications.clone()}
+                            on_change={publication_cb}
+                            style={self.state.citation_style}
+                            on_style_change={citation_style_cb}
+                        />
+                        <CertificationController
+                            value={self.state.certifications.clone()}
+                            on_change={certification_cb}
+                            hide_expired={self.state.hide_expired_certifications}
+                            on_hide_expired_change={hide_expired_cb}
+                        />
+                        <ReferenceController
+                            value={self.state.references.clone()}
+                            on_change={reference_cb}
+                            settings={self.state.reference_settings.clone()}
+                            on_settings_change={reference_settings_cb}
+                        />
                     </div>
                 </aside>
                 <div id="preview-scroll-area" class="flex-1 bg-slate-100 overflow-scroll">
                     <div class="preview-stage">
                         <div id="rusume" class="preview-paper">
-                            <div class="font-['Arial'] text-lg tracking-normal p-10">
-                                <BasicViewer basic={self.state.basic.clone()} />
-                                <SkillViewer skills={self.state.skills.clone()} />
-                                <ExperienceViewer experiences={self.state.experiences.clone()} />
-                                <ProjectViewer projects={self.state.projects.clone()} />
-                                <OpenSourceViewer open_sources={self.state.open_sources.clone()} />
-                                <EducationViewer educations={self.state.educations.clone()} />
+                            <div class={theme.styles().page}>
+                                <BasicViewer basic={shown.basic.clone()} theme={theme} />
+                                {sections}
                             </div>
                         </div>
                     </div>
@@ -173,6 +605,22 @@ impl Component for Home {
     }
 }
 
+impl Home {
+    fn active_variant(&self) -> Option<&Variant> {
+        self.active_variant
+            .and_then(|idx| self.state.variants.get(idx))
+    }
+
+    fn sync_url(&self) {
+        remember_reference_contacts(&self.state.references, &self.state.reference_settings);
+        remember_photo(&self.state.basic.photo);
+        write_state_to_url(
+            &self.state,
+            self.active_variant().map(|variant| variant.name.as_str()),
+        );
+    }
+}
+
 fn scroll_print_view_to_top(window: &web_sys::Window) {
     window.scroll_to_with_x_and_y(0.0, 0.0);
     let Some(document) = window.document() else {
@@ -1,28 +1,176 @@
 use serde::{Deserialize, Serialize};
-use web_sys::HtmlInputElement;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
 use yew::prelude::*;
 
+use super::github_import::GitHubImport;
 use super::html_utils::{
     ADD_BUTTON_CLASS, INPUT_CLASS, INPUT_DIVIDER_CLASS, INPUT_FIELD_WRAPPER_CLASS,
     INPUT_SECTION_CLASS, INPUT_SECTION_ROW_CLASS, LABEL_CLASS, REMOVE_BUTTON_CLASS,
-    SECTION_HEADER_CLASS,
 };
+use super::themes::Theme;
+use super::variants::TagsInput;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum ContributionKind {
+    PullRequest,
+    Issue,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum ContributionStatus {
+    Open,
+    Merged,
+    Closed,
+}
+
+impl ContributionStatus {
+    pub fn all() -> [ContributionStatus; 3] {
+        [
+            ContributionStatus::Merged,
+            ContributionStatus::Open,
+            ContributionStatus::Closed,
+        ]
+    }
+}
 
 #[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
 pub struct OpenSource {
     pub name: String,
     pub url: String,
+    /// Filled in from `url` when it points at a pull request or issue.
+    #[serde(default)]
+    pub owner: String,
+    #[serde(default)]
+    pub repo: String,
+    #[serde(default)]
+    pub number: Option<u32>,
+    #[serde(default)]
+    pub kind: Option<ContributionKind>,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub status: Option<ContributionStatus>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// What a GitHub, GitLab or Codeberg pull request or issue URL points at.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ContributionRef {
+    pub owner: String,
+    pub repo: String,
+    pub number: u32,
+    pub kind: ContributionKind,
+}
+
+/// Parses `github.com/o/r/pull/1`, `gitlab.com/group/sub/r/-/merge_requests/1`,
+/// `codeberg.org/o/r/pulls/1` and the matching issue URLs.
+pub fn parse_contribution_url(url: &str) -> Option<ContributionRef> {
+    let url = url.trim();
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
+    let rest = rest.split(['?', '#']).next().unwrap_or_default();
+    let mut segments = rest.split('/').filter(|segment| !segment.is_empty());
+    let host = segments.next()?.trim_start_matches("www.").to_lowercase();
+    let mut path = segments.collect::<Vec<_>>();
+    // Tabs of a pull request page, e.g. `/pull/222/files`.
+    if path
+        .last()
+        .is_some_and(|tab| matches!(*tab, "files" | "commits" | "checks" | "diffs"))
+    {
+        path.pop();
+    }
+
+    let number = path.pop()?.parse().ok()?;
+    let marker = path.pop()?;
+    let kind = match (host.as_str(), marker) {
+        ("github.com", "pull") | ("codeberg.org", "pulls") => ContributionKind::PullRequest,
+        ("gitlab.com", "merge_requests") => ContributionKind::PullRequest,
+        ("github.com" | "codeberg.org" | "gitlab.com", "issues") => ContributionKind::Issue,
+        _ => return None,
+    };
+    if host == "gitlab.com" && path.pop()? != "-" {
+        return None;
+    }
+    let repo = path.pop()?.to_string();
+    // GitLab repositories may sit in nested groups.
+    let owner = path.join("/");
+    if owner.is_empty() || (host != "gitlab.com" && path.len() != 1) {
+        return None;
+    }
+    Some(ContributionRef {
+        owner,
+        repo,
+        number,
+        kind,
+    })
+}
+
+impl OpenSource {
+    /// An entry filled in from `url`, named like "kafka-rust #222".
+    pub fn from_url(url: &str) -> OpenSource {
+        let mut open_source = OpenSource {
+            url: url.to_string(),
+            ..Default::default()
+        };
+        open_source.fill_from_url();
+        open_source.name = open_source.default_name().unwrap_or_default();
+        open_source
+    }
+
+    /// "repo #number" for entries with a parsed URL.
+    fn default_name(&self) -> Option<String> {
+        Some(format!("{} #{}", self.repo, self.number?))
+    }
+
+    /// Refreshes owner, repo, number and kind from the URL, clearing them
+    /// when it no longer points at a pull request or issue.
+    fn fill_from_url(&mut self) {
+        let parsed = parse_contribution_url(&self.url);
+        self.owner = parsed.as_ref().map(|p| p.owner.clone()).unwrap_or_default();
+        self.repo = parsed.as_ref().map(|p| p.repo.clone()).unwrap_or_default();
+        self.number = parsed.as_ref().map(|p| p.number);
+        self.kind = parsed.map(|p| p.kind);
+    }
+}
+
+/// Entries grouped per repository, in order of first appearance. Entries
+/// without a parsed repository form their own group under their name.
+pub fn group_by_repository(open_sources: &[OpenSource]) -> Vec<(String, Vec<&OpenSource>)> {
+    let mut groups: Vec<(String, String, Vec<&OpenSource>)> = vec![];
+    for open_source in open_sources {
+        let (key, label) = match open_source.repo.as_str() {
+            "" => (open_source.url.clone(), open_source.name.clone()),
+            repo => (
+                format!("{}/{}", open_source.owner, repo).to_lowercase(),
+                repo.to_string(),
+            ),
+        };
+        match groups.iter_mut().find(|(known, _, _)| *known == key) {
+            Some((_, _, entries)) => entries.push(open_source),
+            None => groups.push((key, label, vec![open_source])),
+        }
+    }
+    groups
+        .into_iter()
+        .map(|(_, label, entries)| (label, entries))
+        .collect()
 }
 
 pub enum OpenSourceField {
     Name(String),
     Url(String),
+    Description(String),
 }
 
 #[derive(Properties, PartialEq)]
 pub struct OpenSourceControllerProps {
     pub value: Vec<OpenSource>,
     pub on_change: Callback<Vec<OpenSource>>,
+    pub grouped: bool,
+    pub on_grouped_change: Callback<bool>,
 }
 
 #[function_component(OpenSourceController)]
@@ -36,6 +184,11 @@ pub fn open_source_controller(props: &OpenSourceControllerProps) -> Html {
             on_change.emit(next);
         })
     };
+    let toggle_grouped = {
+        let grouped = props.grouped;
+        let on_change = props.on_grouped_change.clone();
+        Callback::from(move |_| on_change.emit(!grouped))
+    };
 
     let inputs = props
         .value
@@ -54,6 +207,13 @@ pub fn open_source_controller(props: &OpenSourceControllerProps) -> Html {
                 })
             };
 
+            let url_input = make_input(
+                props,
+                idx,
+                "URL".to_string(),
+                open_source.url.clone(),
+                OpenSourceField::Url,
+            );
             let name_input = make_input(
                 props,
                 idx,
@@ -61,19 +221,92 @@ pub fn open_source_controller(props: &OpenSourceControllerProps) -> Html {
                 open_source.name.clone(),
                 OpenSourceField::Name,
             );
-
-            let url_input = make_input(
+            let description_input = make_input(
                 props,
                 idx,
-                "URL".to_string(),
-                open_source.url.clone(),
-                OpenSourceField::Url,
+                "Description".to_string(),
+                open_source.description.clone(),
+                OpenSourceField::Description,
             );
 
+            let parsed = match (open_source.number, open_source.kind) {
+                (Some(number), Some(kind)) => {
+                    let kind = match kind {
+                        ContributionKind::PullRequest => "Pull request",
+                        ContributionKind::Issue => "Issue",
+                    };
+                    html! {
+                        <div class="px-1 text-xs text-slate-400">
+                            {format!("{} #{} in {}/{}", kind, number, open_source.owner, open_source.repo)}
+                        </div>
+                    }
+                }
+                _ => html! {},
+            };
+
+            let update_status = {
+                let open_sources = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |e: Event| {
+                    let select: HtmlSelectElement = e.target_unchecked_into();
+                    let mut next = open_sources.clone();
+                    if let Some(open_source) = next.get_mut(idx) {
+                        open_source.status = ContributionStatus::all()
+                            .into_iter()
+                            .find(|status| format!("{:?}", status) == select.value());
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let status_options = ContributionStatus::all()
+                .into_iter()
+                .map(|status| {
+                    html! {
+                        <option
+                            value={format!("{:?}", status)}
+                            selected={open_source.status == Some(status)}
+                        >
+                            {format!("{:?}", status)}
+                        </option>
+                    }
+                })
+                .collect::<Html>();
+
+            let update_tags = {
+                let open_sources = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |tags: Vec<String>| {
+                    let mut next = open_sources.clone();
+                    if let Some(open_source) = next.get_mut(idx) {
+                        open_source.tags = tags;
+                        on_change.emit(next);
+                    }
+                })
+            };
             html! {
                 <>
-                    {name_input}
                     {url_input}
+                    {parsed}
+                    <div class="flex">
+                        <div class="w-2/3">
+                            {name_input}
+                        </div>
+                        <div class="w-1/3">
+                            <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                                <div class="relative">
+                                    <select class={INPUT_CLASS} onchange={update_status}>
+                                        <option value="" selected={open_source.status.is_none()}>
+                                            {"—"}
+                                        </option>
+                                        {status_options}
+                                    </select>
+                                    <label class={LABEL_CLASS}> {"Status"} </label>
+                                </div>
+                            </div>
+                        </div>
+                    </div>
+                    {description_input}
+                    <TagsInput value={open_source.tags.clone()} on_change={update_tags} />
                     <button
                         class={REMOVE_BUTTON_CLASS}
                         onclick={remove_open_source}
@@ -97,6 +330,16 @@ pub fn open_source_controller(props: &OpenSourceControllerProps) -> Html {
                 </button>
             </div>
             <div class={INPUT_DIVIDER_CLASS}></div>
+            <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                <label class="flex items-center gap-x-1 px-1 py-0.5 text-xs text-slate-300">
+                    <input type="checkbox"
+                           checked={props.grouped}
+                           onclick={toggle_grouped}
+                    />
+                    {"Group contributions by repository"}
+                </label>
+            </div>
+            <GitHubImport existing={props.value.clone()} on_import={props.on_change.clone()} />
             {inputs}
         </>
     }
@@ -120,7 +363,19 @@ where
         if let Some(open_source) = next.get_mut(idx) {
             match cons(input.value()) {
                 OpenSourceField::Name(name) => open_source.name = name,
-                OpenSourceField::Url(url) => open_source.url = url,
+                OpenSourceField::Url(url) => {
+                    // Keep the name in step with the URL until it is edited by hand.
+                    let auto_named = open_source.name.is_empty()
+                        || open_source.default_name().as_ref() == Some(&open_source.name);
+                    open_source.url = url;
+                    open_source.fill_from_url();
+                    if auto_named {
+                        if let Some(name) = open_source.default_name() {
+                            open_source.name = name;
+                        }
+                    }
+                }
+                OpenSourceField::Description(description) => open_source.description = description,
             }
             on_change.emit(next);
         }
@@ -146,6 +401,76 @@ where
 #[derive(Properties, PartialEq)]
 pub struct Props {
     pub open_sources: Vec<OpenSource>,
+    pub heading: String,
+    pub theme: Theme,
+    #[prop_or_default]
+    pub grouped: bool,
+}
+
+fn status_label(open_source: &OpenSource) -> Html {
+    match open_source.status {
+        Some(status) => html! {
+            <span class="text-gray-600"> {format!(" ({:?})", status)} </span>
+        },
+        None => html! {},
+    }
+}
+
+fn view_grouped(open_sources: &[OpenSource]) -> Html {
+    group_by_repository(open_sources)
+        .into_iter()
+        .map(|(label, entries)| {
+            let count = entries.len();
+            let links = entries
+                .into_iter()
+                .enumerate()
+                .map(|(idx, open_source)| {
+                    let text = match open_source.number {
+                        Some(number) => format!("#{}", number),
+                        None => open_source.name.clone(),
+                    };
+                    html! {
+                        <>
+                            <a
+                                href={open_source.url.clone()}
+                                title={open_source.description.clone()}
+                                class="text-blue-600"
+                            >
+                                {text}
+                            </a>
+                            {status_label(open_source)}
+                            {if idx + 1 < count { ", " } else { "" }}
+                        </>
+                    }
+                })
+                .collect::<Html>();
+            html! {
+                <div>
+                    <span class="font-semibold"> {format!("{}: ", label)} </span>
+                    {links}
+                </div>
+            }
+        })
+        .collect::<Html>()
+}
+
+fn view_list(open_sources: &[OpenSource]) -> Html {
+    open_sources
+        .iter()
+        .map(|open_source| {
+            html! {
+                <div>
+                    <a href={open_source.url.clone()} class="text-blue-600">
+                        {&open_source.name}
+                    </a>
+                    {status_label(open_source)}
+                    if !open_source.description.trim().is_empty() {
+                        <span> {format!(" — {}", open_source.description.trim())} </span>
+                    }
+                </div>
+            }
+        })
+        .collect::<Html>()
 }
 
 #[function_component(OpenSourceViewer)]
@@ -153,23 +478,107 @@ pub fn view_open_source(props: &Props) -> Html {
     if props.open_sources.is_empty() {
         return html! {};
     }
-    let open_sources = props
+    let detailed = props
         .open_sources
         .iter()
-        .map(|open_source| {
-            html! {
-                <a href={open_source.url.clone()} class="text-blue-600 mx-2">
-                    {format!("[ {} ]", open_source.name)}
-                </a>
-            }
-        })
-        .collect::<Html>();
-    html! {
-        <>
-            <h5 class={SECTION_HEADER_CLASS}> {"Open Source"} </h5>
+        .any(|o| o.status.is_some() || !o.description.trim().is_empty());
+    let open_sources = if props.grouped {
+        html! {
+            <div class="mx-2 my-1 flex flex-col -space-y-1">
+                {view_grouped(&props.open_sources)}
+            </div>
+        }
+    } else if detailed {
+        html! {
+            <div class="mx-2 my-1 flex flex-col -space-y-1">
+                {view_list(&props.open_sources)}
+            </div>
+        }
+    } else {
+        let links = props
+            .open_sources
+            .iter()
+            .map(|open_source| {
+                html! {
+                    <a href={open_source.url.clone()} class="text-blue-600 mx-2">
+                        {format!("[ {} ]", open_source.name)}
+                    </a>
+                }
+            })
+            .collect::<Html>();
+        html! {
             <div class="mx-2 my-2 flex flex-wrap">
-                {open_sources}
+                {links}
             </div>
+        }
+    };
+    html! {
+        <>
+            <h5 class={props.theme.styles().section_header}> {props.heading.clone()} </h5>
+            {open_sources}
         </>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_pull_request_and_issue_urls() {
+        let parsed = |url| parse_contribution_url(url).map(|p| (p.owner, p.repo, p.number, p.kind));
+        assert_eq!(
+            parsed("https://github.com/kafka-rust/kafka-rust/pull/222/files"),
+            Some((
+                "kafka-rust".to_string(),
+                "kafka-rust".to_string(),
+                222,
+                ContributionKind::PullRequest
+            ))
+        );
+        assert_eq!(
+            parsed("gitlab.com/gitlab-org/cli/glab/-/merge_requests/7?tab=diffs"),
+            Some((
+                "gitlab-org/cli".to_string(),
+                "glab".to_string(),
+                7,
+                ContributionKind::PullRequest
+            ))
+        );
+        assert_eq!(
+            parsed("https://codeberg.org/forgejo/forgejo/issues/31"),
+            Some((
+                "forgejo".to_string(),
+                "forgejo".to_string(),
+                31,
+                ContributionKind::Issue
+            ))
+        );
+        assert_eq!(parsed("https://example.com/a/b/pull/1"), None);
+    }
+
+    #[test]
+    fn groups_contributions_per_repository() {
+        let open_sources = [
+            OpenSource::from_url("https://github.com/kafka-rust/kafka-rust/pull/222"),
+            OpenSource::from_url("https://github.com/gleam-lang/stdlib/pull/769"),
+            OpenSource::from_url("https://github.com/kafka-rust/kafka-rust/pull/223"),
+        ];
+        assert_eq!(open_sources[0].name, "kafka-rust #222");
+
+        let groups = group_by_repository(&open_sources)
+            .into_iter()
+            .map(|(label, entries)| {
+                let numbers = entries.iter().filter_map(|o| o.number).collect::<Vec<_>>();
+                (label, numbers)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            groups,
+            [
+                ("kafka-rust".to_string(), vec![222, 223]),
+                ("stdlib".to_string(), vec![769]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,393 @@
+use std::io::Cursor;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageDecoder, ImageReader, Rgb, RgbImage};
+use serde::{Deserialize, Serialize};
+use web_sys::{window, HtmlSelectElement};
+use yew::prelude::*;
+
+use super::file_input::{FilePicker, LoadedFile};
+use super::html_utils::{INPUT_CLASS, INPUT_FIELD_WRAPPER_CLASS, LABEL_CLASS, SMALL_BUTTON_CLASS};
+
+/// Where the photo is kept when it is left out of the URL.
+const PHOTO_STORAGE_KEY: &str = "rusume-photo";
+
+/// Recompressing stops once the base64 JPEG fits in this many characters.
+const TARGET_SIZE: usize = 12_000;
+
+/// Share links longer than this may be cut off by chat apps and servers.
+const LONG_LINK: usize = 8_000;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum PhotoShape {
+    /// 3:4, as expected on German and Japanese CVs.
+    #[default]
+    Portrait,
+    Square,
+    /// Square, shown as a circle.
+    Round,
+}
+
+impl PhotoShape {
+    pub fn all() -> [PhotoShape; 3] {
+        [PhotoShape::Portrait, PhotoShape::Square, PhotoShape::Round]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PhotoShape::Portrait => "Portrait (3:4)",
+            PhotoShape::Square => "Square",
+            PhotoShape::Round => "Round",
+        }
+    }
+
+    /// Stored size in pixels, about twice the printed size.
+    fn dimensions(&self) -> (u32, u32) {
+        match self {
+            PhotoShape::Portrait => (180, 240),
+            PhotoShape::Square | PhotoShape::Round => (200, 200),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct Photo {
+    /// Base64 JPEG, already cropped and compressed; empty when there is none.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub jpeg: String,
+    #[serde(default)]
+    pub shape: PhotoShape,
+    /// Keeps the photo in this browser instead of share links.
+    #[serde(default)]
+    pub local_only: bool,
+    /// Names this resume's photo in local storage.
+    #[serde(default)]
+    pub storage_id: String,
+}
+
+impl Photo {
+    pub fn is_empty(&self) -> bool {
+        self.jpeg.is_empty()
+    }
+
+    pub fn data_url(&self) -> String {
+        format!("data:image/jpeg;base64,{}", self.jpeg)
+    }
+
+    /// Roughly how many characters the photo adds to a share link; the
+    /// state is base64-encoded again on its way into the URL.
+    pub fn link_cost(&self) -> usize {
+        if self.local_only {
+            0
+        } else {
+            self.jpeg.len() * 4 / 3
+        }
+    }
+}
+
+fn decode(bytes: &[u8]) -> Result<DynamicImage, String> {
+    let unsupported = |_| "Only JPEG and PNG photos are supported".to_string();
+    let mut decoder = ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()
+        .map_err(|e| e.to_string())?
+        .into_decoder()
+        .map_err(unsupported)?;
+    // Phone cameras store rotation separately from the pixels.
+    let orientation = decoder.orientation().map_err(|e| e.to_string())?;
+    let mut image = DynamicImage::from_decoder(decoder).map_err(|e| e.to_string())?;
+    image.apply_orientation(orientation);
+    Ok(image)
+}
+
+/// Flattens transparency onto white, since JPEG has no alpha channel.
+fn on_white(image: &DynamicImage) -> RgbImage {
+    let rgba = image.to_rgba8();
+    RgbImage::from_fn(rgba.width(), rgba.height(), |x, y| {
+        let [r, g, b, a] = rgba.get_pixel(x, y).0;
+        let blend =
+            |channel: u8| ((channel as u32 * a as u32 + 255 * (255 - a as u32)) / 255) as u8;
+        Rgb([blend(r), blend(g), blend(b)])
+    })
+}
+
+/// Center-crops an image to `shape`, scales it down and recompresses it as
+/// JPEG until it is small enough to share. Returns base64.
+pub fn process_photo(bytes: &[u8], shape: PhotoShape) -> Result<String, String> {
+    let (width, height) = shape.dimensions();
+    let image = decode(bytes)?.resize_to_fill(width, height, FilterType::Triangle);
+    let pixels = on_white(&image);
+    let mut encoded = String::new();
+    for quality in [85, 75, 65, 55, 45] {
+        let mut jpeg = vec![];
+        JpegEncoder::new_with_quality(&mut jpeg, quality)
+            .encode_image(&pixels)
+            .map_err(|e| e.to_string())?;
+        encoded = STANDARD.encode(jpeg);
+        if encoded.len() <= TARGET_SIZE {
+            break;
+        }
+    }
+    Ok(encoded)
+}
+
+/// The local storage key for this resume's photo; none before it has an id.
+fn storage_key(photo: &Photo) -> Option<String> {
+    (!photo.storage_id.is_empty()).then(|| format!("{}-{}", PHOTO_STORAGE_KEY, photo.storage_id))
+}
+
+/// Keeps a local-only photo in local storage, so it survives a reload.
+pub fn remember_photo(photo: &Photo) {
+    let Some(key) = storage_key(photo) else {
+        return;
+    };
+    let Some(storage) = window().and_then(|window| window.local_storage().ok().flatten()) else {
+        return;
+    };
+    if photo.local_only && !photo.is_empty() {
+        let _ = storage.set_item(&key, &photo.jpeg);
+    } else {
+        let _ = storage.remove_item(&key);
+    }
+}
+
+/// Brings back a local-only photo that was left out of the URL.
+pub fn restore_photo(photo: &mut Photo) {
+    if !photo.local_only || !photo.is_empty() {
+        return;
+    }
+    let Some(key) = storage_key(photo) else {
+        return;
+    };
+    if let Some(jpeg) = window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(&key).ok().flatten())
+    {
+        photo.jpeg = jpeg;
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PhotoEditorProps {
+    pub value: Photo,
+    pub on_change: Callback<Photo>,
+}
+
+/// Uploads, crops and removes the photo, and warns about share link size.
+#[function_component(PhotoEditor)]
+pub fn photo_editor(props: &PhotoEditorProps) -> Html {
+    // The uploaded file, so changing the shape crops from the original.
+    let original = use_state(|| None::<Vec<u8>>);
+    let error = use_state(String::new);
+
+    let on_load = {
+        let photo = props.value.clone();
+        let on_change = props.on_change.clone();
+        let original = original.clone();
+        let error = error.clone();
+        Callback::from(move |file: Result<LoadedFile, String>| {
+            let processed = file.and_then(|file| {
+                process_photo(&file.bytes, photo.shape).map(|jpeg| (file.bytes, jpeg))
+            });
+            match processed {
+                Ok((bytes, jpeg)) => {
+                    error.set(String::new());
+                    original.set(Some(bytes));
+                    on_change.emit(Photo {
+                        jpeg,
+                        ..photo.clone()
+                    });
+                }
+                Err(message) => error.set(message),
+            }
+        })
+    };
+
+    let update_shape = {
+        let photo = props.value.clone();
+        let on_change = props.on_change.clone();
+        let original = original.clone();
+        let error = error.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            let Some(shape) = PhotoShape::all()
+                .into_iter()
+                .find(|shape| format!("{:?}", shape) == select.value())
+            else {
+                return;
+            };
+            let mut next = Photo {
+                shape,
+                ..photo.clone()
+            };
+            let source = match &*original {
+                Some(bytes) => Some(bytes.clone()),
+                None => STANDARD.decode(&photo.jpeg).ok(),
+            };
+            if let Some(source) = source.filter(|_| !photo.is_empty()) {
+                match process_photo(&source, shape) {
+                    Ok(jpeg) => next.jpeg = jpeg,
+                    Err(message) => error.set(message),
+                }
+            }
+            on_change.emit(next);
+        })
+    };
+    let shape_options = PhotoShape::all()
+        .into_iter()
+        .map(|shape| {
+            html! {
+                <option value={format!("{:?}", shape)} selected={props.value.shape == shape}>
+                    {shape.label()}
+                </option>
+            }
+        })
+        .collect::<Html>();
+
+    let toggle_local_only = {
+        let photo = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_| {
+            on_change.emit(Photo {
+                local_only: !photo.local_only,
+                ..photo.clone()
+            })
+        })
+    };
+    let remove = {
+        let photo = props.value.clone();
+        let on_change = props.on_change.clone();
+        let original = original.clone();
+        Callback::from(move |_| {
+            original.set(None);
+            on_change.emit(Photo {
+                jpeg: String::new(),
+                ..photo.clone()
+            });
+        })
+    };
+
+    let cost = props.value.link_cost();
+    let size_note = if props.value.is_empty() {
+        html! {}
+    } else if props.value.local_only {
+        html! {
+            <p class="px-1 text-xs text-slate-400">
+                {"Kept in this browser only; share links won't include the photo."}
+            </p>
+        }
+    } else {
+        html! {
+            <p class={classes!(
+                "px-1", "text-xs",
+                if cost > LONG_LINK { "text-amber-400" } else { "text-slate-400" }
+            )}>
+                {format!("Adds about {} KB to share links.", cost.div_ceil(1024))}
+                if cost > LONG_LINK {
+                    {" Some apps cut off links this long."}
+                }
+            </p>
+        }
+    };
+
+    html! {
+        <div class={INPUT_FIELD_WRAPPER_CLASS}>
+            <div class="flex items-end gap-x-2">
+                if !props.value.is_empty() {
+                    <img
+                        class="h-16 rounded border border-slate-700"
+                        src={props.value.data_url()}
+                        alt="Photo"
+                    />
+                }
+                <div class="flex-1">
+                    <FilePicker label="Photo" accept="image/jpeg,image/png" on_load={on_load} />
+                </div>
+            </div>
+            <div class="flex items-center gap-x-2 pt-1">
+                <div class="relative flex-1">
+                    <select class={INPUT_CLASS} onchange={update_shape}>
+                        {shape_options}
+                    </select>
+                    <label class={LABEL_CLASS}> {"Photo Crop"} </label>
+                </div>
+                if !props.value.is_empty() {
+                    <button class={SMALL_BUTTON_CLASS} onclick={remove}> {"Remove"} </button>
+                }
+            </div>
+            <label class="flex items-center gap-x-1 px-1 pt-1 text-xs text-slate-300">
+                <input type="checkbox"
+                       checked={props.value.local_only}
+                       onclick={toggle_local_only}
+                />
+                {"Leave the photo out of share links"}
+            </label>
+            {size_note}
+            if !error.is_empty() {
+                <p class="px-1 text-xs text-rose-400"> {(*error).clone()} </p>
+            }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::state::{assign_storage_ids, decode_state, default_seed_state, encode_state};
+    use image::{ImageFormat, Rgba, RgbaImage};
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let image = RgbaImage::from_fn(width, height, |x, y| {
+            Rgba([(x % 256) as u8, (y % 256) as u8, 128, 255])
+        });
+        let mut bytes = Cursor::new(vec![]);
+        image
+            .write_to(&mut bytes, ImageFormat::Png)
+            .expect("PNG should encode");
+        bytes.into_inner()
+    }
+
+    #[test]
+    fn crops_and_shrinks_photos() {
+        let jpeg = process_photo(&png(1200, 900), PhotoShape::Portrait).expect("valid image");
+        assert!(jpeg.len() <= TARGET_SIZE);
+        let image =
+            image::load_from_memory(&STANDARD.decode(jpeg).expect("base64")).expect("valid JPEG");
+        assert_eq!((image.width(), image.height()), (180, 240));
+
+        assert!(process_photo(b"not an image", PhotoShape::Square).is_err());
+    }
+
+    #[test]
+    fn share_urls_leave_out_local_only_photos() {
+        let mut state = default_seed_state();
+        state.basic.photo.jpeg = process_photo(&png(64, 64), PhotoShape::Square).expect("image");
+
+        let shared = decode_state(&encode_state(&state).expect("state should encode"))
+            .expect("state should decode");
+        assert_eq!(shared.basic.photo, state.basic.photo);
+
+        state.basic.photo.local_only = true;
+        let shared = decode_state(&encode_state(&state).expect("state should encode"))
+            .expect("state should decode");
+        assert!(shared.basic.photo.is_empty());
+        assert!(shared.basic.photo.local_only);
+    }
+
+    #[test]
+    fn stored_photos_belong_to_one_resume() {
+        let mut state = default_seed_state();
+        assert_eq!(storage_key(&state.basic.photo), None);
+
+        assign_storage_ids(&mut state);
+        let key = storage_key(&state.basic.photo).expect("an id was assigned");
+        let shared = decode_state(&encode_state(&state).expect("state should encode"))
+            .expect("state should decode");
+        assert_eq!(storage_key(&shared.basic.photo), Some(key.clone()));
+
+        let mut other = default_seed_state();
+        assign_storage_ids(&mut other);
+        assert_ne!(storage_key(&other.basic.photo), Some(key));
+    }
+}
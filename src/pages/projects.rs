@@ -1,26 +1,71 @@
 use serde::{Deserialize, Serialize};
-use web_sys::HtmlInputElement;
+use web_sys::{HtmlInputElement, HtmlTextAreaElement};
 use yew::prelude::*;
 
+use super::bullets::{render_bullets, Bullet, BulletListEditor};
+use super::dates::{current_month, DateFormat, DateRange, DateRangeInput};
 use super::html_utils::{
     ADD_BUTTON_CLASS, INPUT_CLASS, INPUT_DIVIDER_CLASS, INPUT_FIELD_WRAPPER_CLASS,
     INPUT_SECTION_CLASS, INPUT_SECTION_ROW_CLASS, LABEL_CLASS, REMOVE_BUTTON_CLASS,
-    SECTION_HEADER_CLASS,
+    SMALL_ADD_BUTTON_CLASS, SMALL_BUTTON_CLASS, SUBSECTION_LABEL_CLASS, TEXTAREA_CLASS,
 };
+use super::themes::{Theme, ThemeStyles};
+use super::variants::TagsInput;
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct ProjectLink {
+    /// Shown as the link text, e.g. "Repo", "Demo" or "Paper".
+    #[serde(default)]
+    pub label: String,
+    pub url: String,
+}
+
+impl ProjectLink {
+    pub fn new(label: &str, url: &str) -> Self {
+        ProjectLink {
+            label: label.to_string(),
+            url: url.to_string(),
+        }
+    }
+}
 
 #[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
 pub struct Project {
     pub name: String,
     pub description: String,
-    pub technologies: String,
-    pub url: Option<String>,
+    #[serde(default)]
+    pub role: String,
+    #[serde(default)]
+    pub dates: DateRange,
+    #[serde(default)]
+    pub bullets: Vec<Bullet>,
+    /// The first link is also used for the project name.
+    #[serde(default)]
+    pub links: Vec<ProjectLink>,
+    #[serde(default)]
+    pub tech_stack: Vec<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl Project {
+    fn links(&self) -> impl Iterator<Item = &ProjectLink> {
+        self.links.iter().filter(|link| !link.url.trim().is_empty())
+    }
+
+    /// Projects with only a name, description, tech stack and a single link
+    /// keep the one-line layout.
+    fn is_compact(&self) -> bool {
+        self.role.trim().is_empty()
+            && self.dates == DateRange::default()
+            && self.bullets.iter().all(|bullet| bullet.hidden)
+            && self.links().count() <= 1
+    }
 }
 
 pub enum ProjectField {
     Name(String),
-    Description(String),
-    Technologies(String),
-    Url(String),
+    Role(String),
 }
 
 #[derive(Properties, PartialEq)]
@@ -66,36 +111,116 @@ pub fn project_controller(props: &ProjectControllerProps) -> Html {
                 ProjectField::Name,
             );
 
-            let description_input = make_input(
+            let role_input = make_input(
                 props,
                 idx,
-                "Description".to_string(),
-                project.description.clone(),
-                ProjectField::Description,
+                "Role".to_string(),
+                project.role.clone(),
+                ProjectField::Role,
             );
 
-            let technologies_input = make_input(
-                props,
-                idx,
-                "Technologies".to_string(),
-                project.technologies.clone(),
-                ProjectField::Technologies,
-            );
+            let update_description = {
+                let projects = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |e: InputEvent| {
+                    let input: HtmlTextAreaElement = e.target_unchecked_into();
+                    let mut next = projects.clone();
+                    if let Some(project) = next.get_mut(idx) {
+                        project.description = input.value();
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let description_input = html! {
+                <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                    <div class="relative">
+                        <textarea
+                            class={TEXTAREA_CLASS}
+                            style="min-height: 64px"
+                            oninput={update_description}
+                            value={project.description.clone()}
+                        />
+                        <label class={LABEL_CLASS}> {"Description"} </label>
+                    </div>
+                </div>
+            };
 
-            let url_input = make_input(
-                props,
-                idx,
-                "Url".to_string(),
-                project.url.clone().unwrap_or_default(),
-                ProjectField::Url,
-            );
+            let update_dates = {
+                let projects = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |dates: DateRange| {
+                    let mut next = projects.clone();
+                    if let Some(project) = next.get_mut(idx) {
+                        project.dates = dates;
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let update_links = {
+                let projects = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |links: Vec<ProjectLink>| {
+                    let mut next = projects.clone();
+                    if let Some(project) = next.get_mut(idx) {
+                        project.links = links;
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let update_bullets = {
+                let projects = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |bullets: Vec<Bullet>| {
+                    let mut next = projects.clone();
+                    if let Some(project) = next.get_mut(idx) {
+                        project.bullets = bullets;
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let update_tech_stack = {
+                let projects = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |tech_stack: Vec<String>| {
+                    let mut next = projects.clone();
+                    if let Some(project) = next.get_mut(idx) {
+                        project.tech_stack = tech_stack;
+                        on_change.emit(next);
+                    }
+                })
+            };
 
+            let update_tags = {
+                let projects = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |tags: Vec<String>| {
+                    let mut next = projects.clone();
+                    if let Some(project) = next.get_mut(idx) {
+                        project.tags = tags;
+                        on_change.emit(next);
+                    }
+                })
+            };
             html! {
                 <>
-                    {name_input}
+                    <div class="flex">
+                        <div class="w-1/2">
+                            {name_input}
+                        </div>
+                        <div class="w-1/2">
+                            {role_input}
+                        </div>
+                    </div>
                     {description_input}
-                    {technologies_input}
-                    {url_input}
+                    <DateRangeInput value={project.dates.clone()} on_change={update_dates} />
+                    <LinkListEditor value={project.links.clone()} on_change={update_links} />
+                    <BulletListEditor value={project.bullets.clone()} on_change={update_bullets} />
+                    <TagsInput
+                        label="Tech Stack"
+                        value={project.tech_stack.clone()}
+                        on_change={update_tech_stack}
+                    />
+                    <TagsInput value={project.tags.clone()} on_change={update_tags} />
                     <button
                         class={REMOVE_BUTTON_CLASS}
                         onclick={remove_project}
@@ -141,9 +266,7 @@ where
         if let Some(project) = next.get_mut(idx) {
             match cons(input.value()) {
                 ProjectField::Name(name) => project.name = name,
-                ProjectField::Description(description) => project.description = description,
-                ProjectField::Technologies(technologies) => project.technologies = technologies,
-                ProjectField::Url(url) => project.url = Some(url),
+                ProjectField::Role(role) => project.role = role,
             }
             on_change.emit(next);
         }
@@ -166,9 +289,176 @@ where
     }
 }
 
+#[derive(Properties, PartialEq)]
+struct LinkListEditorProps {
+    value: Vec<ProjectLink>,
+    on_change: Callback<Vec<ProjectLink>>,
+}
+
+#[function_component(LinkListEditor)]
+fn link_list_editor(props: &LinkListEditorProps) -> Html {
+    let update = |idx: usize, apply: fn(&mut ProjectLink, String)| {
+        let links = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut next = links.clone();
+            if let Some(link) = next.get_mut(idx) {
+                apply(link, input.value());
+                on_change.emit(next);
+            }
+        })
+    };
+
+    let rows = props
+        .value
+        .iter()
+        .enumerate()
+        .map(|(idx, link)| {
+            let remove = {
+                let links = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |_| {
+                    let mut next = links.clone();
+                    if idx < next.len() {
+                        next.remove(idx);
+                        on_change.emit(next);
+                    }
+                })
+            };
+            html! {
+                <div class="grid grid-cols-[7rem_minmax(0,1fr)_auto] items-center gap-1">
+                    <div class="relative">
+                        <input type="text"
+                               class={INPUT_CLASS}
+                               placeholder="Repo"
+                               value={link.label.clone()}
+                               oninput={update(idx, |l, v| l.label = v)}
+                        />
+                        <label class={LABEL_CLASS}> {"Label"} </label>
+                    </div>
+                    <div class="relative">
+                        <input type="text"
+                               class={INPUT_CLASS}
+                               value={link.url.clone()}
+                               oninput={update(idx, |l, v| l.url = v)}
+                        />
+                        <label class={LABEL_CLASS}> {"Url"} </label>
+                    </div>
+                    <button class={SMALL_BUTTON_CLASS} onclick={remove}> {"Remove"} </button>
+                </div>
+            }
+        })
+        .collect::<Html>();
+
+    let add = {
+        let links = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_| {
+            let mut next = links.clone();
+            next.push(ProjectLink::default());
+            on_change.emit(next);
+        })
+    };
+
+    html! {
+        <div class={INPUT_FIELD_WRAPPER_CLASS}>
+            <div class="flex items-center justify-between px-1 py-1">
+                <div class={SUBSECTION_LABEL_CLASS}>{"Links"}</div>
+                <button class={SMALL_ADD_BUTTON_CLASS} onclick={add}>
+                    {"Add Link"}
+                </button>
+            </div>
+            {rows}
+        </div>
+    }
+}
+
+fn project_name(project: &Project) -> Html {
+    match project.links().next() {
+        Some(link) => html! {
+            <a href={link.url.clone()} class="text-blue-600"> {project.name.clone()} </a>
+        },
+        None => html! { <span> {project.name.clone()} </span> },
+    }
+}
+
+fn view_compact(project: &Project) -> Html {
+    html! {
+        <div class="flex justify-between">
+            <div>
+                {project_name(project)} {" : "}
+                {project.description.clone()}
+            </div>
+            <div class="font-bold"> {project.tech_stack.join(", ")} </div>
+        </div>
+    }
+}
+
+fn view_detailed(project: &Project, date_format: &DateFormat, styles: &ThemeStyles) -> Html {
+    let role = if project.role.trim().is_empty() {
+        html! {}
+    } else {
+        html! {
+            <>
+                <span> {" | "} </span>
+                <span> {project.role.trim()} </span>
+            </>
+        }
+    };
+    let links = project
+        .links()
+        .enumerate()
+        .map(|(idx, link)| {
+            let label = match link.label.trim() {
+                "" => link.url.clone(),
+                label => label.to_string(),
+            };
+            html! {
+                <>
+                    if idx > 0 { <span> {" · "} </span> }
+                    <a href={link.url.clone()} class="text-blue-600"> {label} </a>
+                </>
+            }
+        })
+        .collect::<Html>();
+    let tech_stack = if project.tech_stack.is_empty() {
+        html! {}
+    } else {
+        html! { <span class="font-bold"> {project.tech_stack.join(", ")} </span> }
+    };
+    html! {
+        <div class="mb-1">
+            <div class={styles.entry_row}>
+                <div class="flex gap-x-1 min-w-0">
+                    <span class="font-bold"> {project_name(project)} </span>
+                    {role}
+                </div>
+                <div class={styles.entry_meta}>
+                    {tech_stack}
+                    if project.dates != DateRange::default() {
+                        if !project.tech_stack.is_empty() { <span> {" | "} </span> }
+                        <span> {project.dates.format(date_format, current_month())} </span>
+                    }
+                </div>
+            </div>
+            if !project.description.trim().is_empty() {
+                <div> {project.description.clone()} </div>
+            }
+            if project.links().count() > 1 {
+                <div class="text-base"> {links} </div>
+            }
+            {render_bullets(&project.bullets)}
+        </div>
+    }
+}
+
 #[derive(Properties, PartialEq)]
 pub struct Props {
     pub projects: Vec<Project>,
+    pub heading: String,
+    pub theme: Theme,
+    pub date_format: DateFormat,
 }
 
 #[function_component(ProjectViewer)]
@@ -180,28 +470,45 @@ pub fn view_project(props: &Props) -> Html {
         .projects
         .iter()
         .map(|project| {
-            let name_maybe_with_url = if project.url.is_some() {
-                html! { <a href={project.url.clone().unwrap_or_default()} class="text-blue-600"> {project.name.clone()} </a> }
+            if project.is_compact() {
+                view_compact(project)
             } else {
-                html! { <div> {project.name.clone()} </div> }
-            };
-            html! {
-                <div class="flex justify-between">
-                    <div>
-                        {name_maybe_with_url} {" : "}
-                        {project.description.clone()}
-                    </div>
-                    <div class="font-bold"> {project.technologies.clone()} </div>
-                </div>
+                view_detailed(project, &props.date_format, props.theme.styles())
             }
         })
         .collect::<Html>();
     html! {
         <>
-        <h5 class={SECTION_HEADER_CLASS}> {"Projects"} </h5>
+        <h5 class={props.theme.styles().section_header}> {props.heading.clone()} </h5>
         <div class="m-2 flex flex-col space-y-[0.5]">
             {projects}
         </div>
         </>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basic_projects_stay_compact() {
+        let mut project = Project {
+            name: "Rusume".to_string(),
+            description: "Resume builder".to_string(),
+            links: vec![ProjectLink::new("", "https://example.com")],
+            tech_stack: vec!["Rust".to_string()],
+            ..Default::default()
+        };
+        assert!(project.is_compact());
+
+        project
+            .links
+            .push(ProjectLink::new("Demo", "https://demo.example.com"));
+        assert!(!project.is_compact());
+
+        project.links.truncate(1);
+        project.dates = DateRange::parse("2024/01 - 2024/06");
+        assert!(!project.is_compact());
+    }
+}
@@ -0,0 +1,806 @@
+use serde::{Deserialize, Serialize};
+use web_sys::{HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement};
+use yew::prelude::*;
+
+use super::bullets::render_markdown;
+use super::html_utils::{
+    ADD_BUTTON_CLASS, INPUT_CLASS, INPUT_DIVIDER_CLASS, INPUT_FIELD_WRAPPER_CLASS,
+    INPUT_SECTION_CLASS, INPUT_SECTION_ROW_CLASS, LABEL_CLASS, REMOVE_BUTTON_CLASS,
+    SMALL_ADD_BUTTON_CLASS, TEXTAREA_CLASS,
+};
+use super::themes::Theme;
+use super::variants::TagsInput;
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct Publication {
+    /// Author names in "First Last" order.
+    #[serde(default)]
+    pub authors: Vec<String>,
+    pub title: String,
+    #[serde(default)]
+    pub venue: String,
+    #[serde(default)]
+    pub year: Option<u16>,
+    #[serde(default)]
+    pub doi: String,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum CitationStyle {
+    #[default]
+    Short,
+    Apa,
+    Ieee,
+}
+
+// BibTeX import
+
+struct BibParser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl BibParser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn ident(&mut self) -> String {
+        self.skip_whitespace();
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_alphanumeric() || "_-:.+/".contains(c))
+        {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    /// Reads up to the matching `close`, keeping nested braces.
+    fn delimited(&mut self, close: char) -> String {
+        let mut depth = 0;
+        let mut text = String::new();
+        while let Some(c) = self.peek() {
+            self.pos += 1;
+            match c {
+                '{' => depth += 1,
+                '}' if depth > 0 => depth -= 1,
+                c if c == close && depth == 0 => return text,
+                _ => {}
+            }
+            text.push(c);
+        }
+        text
+    }
+
+    /// A field value: `{...}`, `"..."` or a bare number/macro, possibly
+    /// joined with `#`.
+    fn value(&mut self) -> String {
+        let mut value = String::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('{') => {
+                    self.pos += 1;
+                    value.push_str(&self.delimited('}'));
+                }
+                Some('"') => {
+                    self.pos += 1;
+                    value.push_str(&self.delimited('"'));
+                }
+                _ => value.push_str(&self.ident()),
+            }
+            if !self.eat('#') {
+                return value;
+            }
+        }
+    }
+
+    fn entry(&mut self) -> Option<(String, Vec<(String, String)>)> {
+        let kind = self.ident().to_lowercase();
+        let close = if self.eat('{') {
+            '}'
+        } else if self.eat('(') {
+            ')'
+        } else {
+            return None;
+        };
+        if matches!(kind.as_str(), "comment" | "preamble" | "string") {
+            self.delimited(close);
+            return None;
+        }
+        // The citation key.
+        while self.peek().is_some_and(|c| c != ',' && c != close) {
+            self.pos += 1;
+        }
+        self.eat(',');
+
+        let mut fields = vec![];
+        loop {
+            if self.eat(close) || self.peek().is_none() {
+                break;
+            }
+            let name = self.ident().to_lowercase();
+            if name.is_empty() || !self.eat('=') {
+                // Skip whatever we can't read up to the next field.
+                while self.peek().is_some_and(|c| c != ',' && c != close) {
+                    self.pos += 1;
+                }
+                self.eat(',');
+                continue;
+            }
+            let value = self.value();
+            fields.push((name, clean_bib_text(&value)));
+            self.eat(',');
+        }
+        Some((kind, fields))
+    }
+}
+
+fn clean_bib_text(text: &str) -> String {
+    text.replace(['{', '}'], "")
+        .replace("\\&", "&")
+        .replace("---", "—")
+        .replace("--", "–")
+        .replace('~', " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Turns a BibTeX "Last, First" name into "First Last".
+fn bib_author(name: &str) -> String {
+    let parts = name.split(',').map(str::trim).collect::<Vec<_>>();
+    match parts.as_slice() {
+        [last, first] => format!("{} {}", first, last),
+        [last, suffix, first] => format!("{} {} {}", first, last, suffix),
+        _ => name.trim().to_string(),
+    }
+}
+
+/// Parses every entry of a `.bib` file into publications.
+pub fn parse_bibtex(input: &str) -> Vec<Publication> {
+    let mut parser = BibParser {
+        chars: input.chars().collect(),
+        pos: 0,
+    };
+    let mut publications = vec![];
+    while let Some(offset) = parser.chars[parser.pos..].iter().position(|c| *c == '@') {
+        parser.pos += offset + 1;
+        let Some((_, fields)) = parser.entry() else {
+            continue;
+        };
+        let field = |names: &[&str]| {
+            names
+                .iter()
+                .find_map(|name| fields.iter().find(|(key, _)| key == name))
+                .map(|(_, value)| value.clone())
+                .unwrap_or_default()
+        };
+        let authors = field(&["author"])
+            .split(" and ")
+            .map(str::trim)
+            .filter(|name| !name.is_empty() && *name != "others")
+            .map(bib_author)
+            .collect();
+        let doi = field(&["doi"]);
+        publications.push(Publication {
+            authors,
+            title: field(&["title"]),
+            venue: field(&[
+                "journal",
+                "booktitle",
+                "publisher",
+                "school",
+                "institution",
+                "howpublished",
+            ]),
+            year: field(&["year"]).trim().parse().ok(),
+            doi: doi
+                .trim_start_matches("https://doi.org/")
+                .trim_start_matches("http://dx.doi.org/")
+                .to_string(),
+            url: field(&["url"]),
+            tags: vec![],
+        });
+    }
+    publications
+}
+
+/// Appends `imported` to `existing`, skipping entries with a DOI or title
+/// that is already listed. Returns the number of entries added.
+pub fn merge_publications(existing: &mut Vec<Publication>, imported: Vec<Publication>) -> usize {
+    let same = |a: &Publication, b: &Publication| {
+        (!a.doi.is_empty() && a.doi.eq_ignore_ascii_case(&b.doi))
+            || a.title.trim().eq_ignore_ascii_case(b.title.trim())
+    };
+    let before = existing.len();
+    for publication in imported {
+        if !existing.iter().any(|known| same(known, &publication)) {
+            existing.push(publication);
+        }
+    }
+    existing.len() - before
+}
+
+// Citation formatting
+
+fn name_words(name: &str) -> Vec<String> {
+    name.split(|c: char| c.is_whitespace() || c == '.')
+        .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric() && c != '-'))
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Whether `author` refers to the resume owner: same last name, and a first
+/// name or initial that matches one of the owner's given names or nicknames.
+pub fn is_owner(author: &str, owner: &str) -> bool {
+    let author = name_words(author);
+    let owner = name_words(owner);
+    let (Some((author_last, author_given)), Some((owner_last, owner_given))) =
+        (author.split_last(), owner.split_last())
+    else {
+        return false;
+    };
+    if author_last != owner_last {
+        return false;
+    }
+    match author_given.first() {
+        None => true,
+        Some(first) if first.chars().count() == 1 => owner_given
+            .iter()
+            .any(|given| given.starts_with(first.as_str())),
+        Some(first) => owner_given.contains(first),
+    }
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if "\\`*_[]<>#".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn split_name(name: &str) -> (Vec<&str>, &str) {
+    let mut words = name.split_whitespace().collect::<Vec<_>>();
+    let last = words.pop().unwrap_or_default();
+    (words, last)
+}
+
+fn initials(given: &[&str]) -> String {
+    given
+        .iter()
+        .filter_map(|word| word.chars().find(|c| c.is_alphabetic()))
+        .map(|c| format!("{}.", c.to_uppercase()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn styled_author(author: &str, style: CitationStyle) -> String {
+    let (given, last) = split_name(author);
+    let initials = initials(&given);
+    match style {
+        CitationStyle::Short => author.trim().to_string(),
+        CitationStyle::Apa if initials.is_empty() => last.to_string(),
+        CitationStyle::Apa => format!("{}, {}", last, initials),
+        CitationStyle::Ieee if initials.is_empty() => last.to_string(),
+        CitationStyle::Ieee => format!("{} {}", initials, last),
+    }
+}
+
+fn author_list(authors: &[String], style: CitationStyle, owner: &str) -> String {
+    let mut names = authors
+        .iter()
+        .map(|author| {
+            let name = escape_markdown(&styled_author(author, style));
+            if is_owner(author, owner) {
+                format!("**{}**", name)
+            } else {
+                name
+            }
+        })
+        .collect::<Vec<_>>();
+    let (limit, conjunction) = match style {
+        CitationStyle::Short => (3, ""),
+        CitationStyle::Apa => (20, "& "),
+        CitationStyle::Ieee => (6, "and "),
+    };
+    if names.len() > limit {
+        // Keep the owner visible even when the list is cut short.
+        let owner_idx = authors.iter().position(|author| is_owner(author, owner));
+        let shown = match owner_idx {
+            Some(idx) if idx >= limit => {
+                vec![names[0].clone(), "…".to_string(), names[idx].clone()]
+            }
+            _ => names[..limit].to_vec(),
+        };
+        return format!("{} et al.", shown.join(", "));
+    }
+    match names.len() {
+        0 => String::new(),
+        1 => names.remove(0),
+        2 if style == CitationStyle::Ieee => format!("{} and {}", names[0], names[1]),
+        _ => {
+            let last = names.pop().unwrap_or_default();
+            format!("{}, {}{}", names.join(", "), conjunction, last)
+        }
+    }
+}
+
+/// Formats a publication as a Markdown citation, bolding the owner's name.
+pub fn format_citation(publication: &Publication, style: CitationStyle, owner: &str) -> String {
+    let authors = author_list(&publication.authors, style, owner);
+    let title = match publication.url.trim() {
+        "" => escape_markdown(publication.title.trim()),
+        url => format!("[{}]({})", escape_markdown(publication.title.trim()), url),
+    };
+    let venue = match publication.venue.trim() {
+        "" => String::new(),
+        venue => format!("*{}*", escape_markdown(venue)),
+    };
+    let doi = publication.doi.trim();
+    let year = publication.year.map(|year| year.to_string());
+
+    let mut parts: Vec<String> = vec![];
+    match style {
+        CitationStyle::Short => {
+            parts.extend([authors, title].into_iter().filter(|p| !p.is_empty()));
+            let venue_year = [venue, year.unwrap_or_default()]
+                .into_iter()
+                .filter(|p| !p.is_empty())
+                .collect::<Vec<_>>()
+                .join(", ");
+            if !venue_year.is_empty() {
+                parts.push(venue_year);
+            }
+            format!("{}.", parts.join(". "))
+        }
+        CitationStyle::Apa => {
+            let year = format!("({})", year.as_deref().unwrap_or("n.d."));
+            parts.push(match authors.as_str() {
+                "" => year,
+                authors => format!("{} {}", authors, year),
+            });
+            parts.extend([title, venue].into_iter().filter(|p| !p.is_empty()));
+            let mut citation = format!("{}.", parts.join(". "));
+            if !doi.is_empty() {
+                citation.push_str(&format!(" <https://doi.org/{}>", doi));
+            }
+            citation
+        }
+        CitationStyle::Ieee => {
+            let mut details = [venue, year.unwrap_or_default()]
+                .into_iter()
+                .filter(|p| !p.is_empty())
+                .collect::<Vec<_>>();
+            if !doi.is_empty() {
+                details.push(format!("doi: {}", escape_markdown(doi)));
+            }
+            // The comma sits inside the quotes, and only when details follow.
+            let title = match (title.is_empty(), details.is_empty()) {
+                (true, _) => title,
+                (false, true) => format!("\"{}\"", title),
+                (false, false) => format!("\"{},\"", title),
+            };
+            parts.extend([authors, title].into_iter().filter(|p| !p.is_empty()));
+            let head = parts.join(", ");
+            match details.is_empty() {
+                true => head + ".",
+                false => format!("{} {}.", head, details.join(", ")),
+            }
+        }
+    }
+}
+
+// Editor and viewer
+
+pub enum PublicationField {
+    Title(String),
+    Venue(String),
+    Year(String),
+    Doi(String),
+    Url(String),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PublicationControllerProps {
+    pub value: Vec<Publication>,
+    pub on_change: Callback<Vec<Publication>>,
+    pub style: CitationStyle,
+    pub on_style_change: Callback<CitationStyle>,
+}
+
+#[function_component(PublicationController)]
+pub fn publication_controller(props: &PublicationControllerProps) -> Html {
+    let bibtex = use_state(String::new);
+    let import_message = use_state(String::new);
+
+    let add_publication = {
+        let publications = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_| {
+            let mut next = publications.clone();
+            next.push(Publication::default());
+            on_change.emit(next);
+        })
+    };
+
+    let update_bibtex = {
+        let bibtex = bibtex.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlTextAreaElement = e.target_unchecked_into();
+            bibtex.set(input.value());
+        })
+    };
+    let import_bibtex = {
+        let bibtex = bibtex.clone();
+        let import_message = import_message.clone();
+        let publications = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_| {
+            let imported = parse_bibtex(&bibtex);
+            let found = imported.len();
+            let mut next = publications.clone();
+            let added = merge_publications(&mut next, imported);
+            import_message.set(format!(
+                "Imported {} of {} entries; {} already listed.",
+                added,
+                found,
+                found - added
+            ));
+            if added > 0 {
+                bibtex.set(String::new());
+                on_change.emit(next);
+            }
+        })
+    };
+
+    let update_style = {
+        let on_style_change = props.on_style_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            let style = match select.value().as_str() {
+                "Apa" => CitationStyle::Apa,
+                "Ieee" => CitationStyle::Ieee,
+                _ => CitationStyle::Short,
+            };
+            on_style_change.emit(style);
+        })
+    };
+    let style_options = [
+        (CitationStyle::Short, "Short"),
+        (CitationStyle::Apa, "APA-like"),
+        (CitationStyle::Ieee, "IEEE-like"),
+    ]
+    .into_iter()
+    .map(|(style, label)| {
+        html! {
+            <option value={format!("{:?}", style)} selected={props.style == style}>
+                {label}
+            </option>
+        }
+    })
+    .collect::<Html>();
+
+    let inputs = props
+        .value
+        .iter()
+        .enumerate()
+        .map(|(idx, publication)| {
+            let remove_publication = {
+                let publications = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |_| {
+                    let mut next = publications.clone();
+                    if idx < next.len() {
+                        next.remove(idx);
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let title_input = make_input(
+                props,
+                idx,
+                "Title".to_string(),
+                publication.title.clone(),
+                PublicationField::Title,
+            );
+            let venue_input = make_input(
+                props,
+                idx,
+                "Venue".to_string(),
+                publication.venue.clone(),
+                PublicationField::Venue,
+            );
+            let year_input = make_input(
+                props,
+                idx,
+                "Year".to_string(),
+                publication.year.map(|y| y.to_string()).unwrap_or_default(),
+                PublicationField::Year,
+            );
+            let doi_input = make_input(
+                props,
+                idx,
+                "DOI".to_string(),
+                publication.doi.clone(),
+                PublicationField::Doi,
+            );
+            let url_input = make_input(
+                props,
+                idx,
+                "URL".to_string(),
+                publication.url.clone(),
+                PublicationField::Url,
+            );
+            let update_list = |apply: fn(&mut Publication, Vec<String>)| {
+                let publications = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |values: Vec<String>| {
+                    let mut next = publications.clone();
+                    if let Some(publication) = next.get_mut(idx) {
+                        apply(publication, values);
+                        on_change.emit(next);
+                    }
+                })
+            };
+            html! {
+                <>
+                    {title_input}
+                    <TagsInput
+                        label="Authors"
+                        value={publication.authors.clone()}
+                        on_change={update_list(|p, authors| p.authors = authors)}
+                    />
+                    <div class="grid grid-cols-[minmax(0,1fr)_6rem]">
+                        {venue_input}
+                        {year_input}
+                    </div>
+                    <div class="grid grid-cols-2">
+                        {doi_input}
+                        {url_input}
+                    </div>
+                    <TagsInput
+                        value={publication.tags.clone()}
+                        on_change={update_list(|p, tags| p.tags = tags)}
+                    />
+                    <button
+                        class={REMOVE_BUTTON_CLASS}
+                        onclick={remove_publication}
+                    >
+                        {"Remove"}
+                    </button>
+                </>
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <>
+            <div class={INPUT_SECTION_ROW_CLASS}>
+                <h5 class={INPUT_SECTION_CLASS}> {"Publications"} </h5>
+                <button
+                    class={ADD_BUTTON_CLASS}
+                    onclick={add_publication}
+                >
+                    {"Add New"}
+                </button>
+            </div>
+            <div class={INPUT_DIVIDER_CLASS}></div>
+            <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                <div class="relative">
+                    <select class={INPUT_CLASS} onchange={update_style}>
+                        {style_options}
+                    </select>
+                    <label class={LABEL_CLASS}> {"Citation Style"} </label>
+                </div>
+            </div>
+            <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                <div class="relative">
+                    <textarea
+                        class={TEXTAREA_CLASS}
+                        style="min-height: 64px"
+                        placeholder="@article{key, author = {Last, First and ...}, title = {...}}"
+                        oninput={update_bibtex}
+                        value={(*bibtex).clone()}
+                    />
+                    <label class={LABEL_CLASS}> {"Paste BibTeX"} </label>
+                </div>
+                <div class="mt-1 flex items-center justify-end gap-x-2">
+                    <span class="text-xs text-slate-400"> {(*import_message).clone()} </span>
+                    <button class={SMALL_ADD_BUTTON_CLASS} onclick={import_bibtex}>
+                        {"Import BibTeX"}
+                    </button>
+                </div>
+            </div>
+            {inputs}
+        </>
+    }
+}
+
+fn make_input<F>(
+    props: &PublicationControllerProps,
+    idx: usize,
+    name: String,
+    value: String,
+    cons: F,
+) -> Html
+where
+    F: Fn(String) -> PublicationField + 'static,
+{
+    let publications = props.value.clone();
+    let on_change = props.on_change.clone();
+    let callback = Callback::from(move |e: InputEvent| {
+        let input: HtmlInputElement = e.target_unchecked_into();
+        let mut next = publications.clone();
+        if let Some(publication) = next.get_mut(idx) {
+            match cons(input.value()) {
+                PublicationField::Title(title) => publication.title = title,
+                PublicationField::Venue(venue) => publication.venue = venue,
+                PublicationField::Year(year) => publication.year = year.trim().parse().ok(),
+                PublicationField::Doi(doi) => publication.doi = doi,
+                PublicationField::Url(url) => publication.url = url,
+            }
+            on_change.emit(next);
+        }
+    });
+    html! {
+    <div class={INPUT_FIELD_WRAPPER_CLASS}>
+        <div class="relative">
+            <input type="text"
+                   id={name.clone()}
+                   oninput={callback}
+                   class={INPUT_CLASS}
+                   value={value}
+            />
+            <label for={name.clone()}
+                   class={LABEL_CLASS}>
+                {name}
+            </label>
+        </div>
+    </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub publications: Vec<Publication>,
+    pub heading: String,
+    pub theme: Theme,
+    pub style: CitationStyle,
+    /// The resume owner's name, bolded in author lists.
+    pub owner: String,
+}
+
+#[function_component(PublicationViewer)]
+pub fn view_publication(props: &Props) -> Html {
+    if props.publications.is_empty() {
+        return html! {};
+    }
+    let publications = props
+        .publications
+        .iter()
+        .map(|publication| {
+            let citation = format_citation(publication, props.style, &props.owner);
+            html! { <li> {render_markdown(&citation)} </li> }
+        })
+        .collect::<Html>();
+    let list_class = match props.style {
+        CitationStyle::Ieee => "mx-2 my-1 ml-8 list-[lower-roman] list-outside",
+        CitationStyle::Short | CitationStyle::Apa => "mx-2 my-1",
+    };
+    html! {
+        <>
+            <h5 class={props.theme.styles().section_header}> {props.heading.clone()} </h5>
+            <ol class={list_class}>
+                {publications}
+            </ol>
+        </>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BIB: &str = r#"
+        @comment{exported from a reference manager}
+        @inproceedings{chen2023greenevt,
+          author    = {Chen, Yilun and Doe, Jane and others},
+          title     = {{GreenEVT}: Greensboro Electric Vehicle Testbed},
+          booktitle = "Proceedings of the Grid Conference",
+          year      = 2023,
+          doi       = {10.1000/xyz123},
+        }
+        @article(key2, title = {Swarm Robotics}, journal = {Robotics} # { Letters}, year = {2021})
+    "#;
+
+    #[test]
+    fn parses_bibtex_entries() {
+        let publications = parse_bibtex(BIB);
+        assert_eq!(publications.len(), 2);
+        assert_eq!(publications[0].authors, ["Yilun Chen", "Jane Doe"]);
+        assert_eq!(
+            publications[0].title,
+            "GreenEVT: Greensboro Electric Vehicle Testbed"
+        );
+        assert_eq!(publications[0].venue, "Proceedings of the Grid Conference");
+        assert_eq!(publications[0].year, Some(2023));
+        assert_eq!(publications[0].doi, "10.1000/xyz123");
+        assert_eq!(publications[1].venue, "Robotics Letters");
+
+        let mut existing = publications.clone();
+        assert_eq!(merge_publications(&mut existing, parse_bibtex(BIB)), 0);
+    }
+
+    #[test]
+    fn matches_the_owner_by_name_or_initial() {
+        let owner = "Yilun \"Allen\" Chen";
+        assert!(is_owner("Yilun Chen", owner));
+        assert!(is_owner("Y. Chen", owner));
+        assert!(is_owner("Allen Chen", owner));
+        assert!(!is_owner("Jane Chen", owner));
+        assert!(!is_owner("Yilun Doe", owner));
+    }
+
+    #[test]
+    fn formats_citation_styles() {
+        let publication = Publication {
+            authors: vec!["Yilun Chen".to_string(), "Jane Q Doe".to_string()],
+            title: "Swarm Robotics".to_string(),
+            venue: "Robotics Letters".to_string(),
+            year: Some(2021),
+            doi: "10.1/abc".to_string(),
+            ..Default::default()
+        };
+        let owner = "Yilun Chen";
+        assert_eq!(
+            format_citation(&publication, CitationStyle::Short, owner),
+            "**Yilun Chen**, Jane Q Doe. Swarm Robotics. *Robotics Letters*, 2021."
+        );
+        assert_eq!(
+            format_citation(&publication, CitationStyle::Apa, owner),
+            "**Chen, Y.**, & Doe, J. Q. (2021). Swarm Robotics. *Robotics Letters*. <https://doi.org/10.1/abc>"
+        );
+        assert_eq!(
+            format_citation(&publication, CitationStyle::Ieee, owner),
+            "**Y. Chen** and J. Q. Doe, \"Swarm Robotics,\" *Robotics Letters*, 2021, doi: 10.1/abc."
+        );
+
+        let bare = Publication {
+            authors: vec!["Jane Q Doe".to_string()],
+            title: "Swarm Robotics".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            format_citation(&bare, CitationStyle::Ieee, owner),
+            "J. Q. Doe, \"Swarm Robotics\"."
+        );
+    }
+}
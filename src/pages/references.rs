@@ -0,0 +1,490 @@
+use serde::{Deserialize, Serialize};
+use web_sys::{window, HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use super::html_utils::{
+    ADD_BUTTON_CLASS, INPUT_CLASS, INPUT_DIVIDER_CLASS, INPUT_FIELD_WRAPPER_CLASS,
+    INPUT_SECTION_CLASS, INPUT_SECTION_ROW_CLASS, LABEL_CLASS, REMOVE_BUTTON_CLASS,
+};
+use super::themes::Theme;
+use super::variants::TagsInput;
+
+/// Where reference contact details are kept when they are left out of the URL.
+const CONTACTS_STORAGE_KEY: &str = "rusume-reference-contacts";
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct Reference {
+    pub name: String,
+    #[serde(default)]
+    pub relationship: String,
+    #[serde(default)]
+    pub company: String,
+    #[serde(default)]
+    pub email: String,
+    #[serde(default)]
+    pub phone: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum ReferenceMode {
+    #[default]
+    Full,
+    /// Only states that references are available upon request.
+    OnRequest,
+    Hidden,
+}
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct ReferenceSettings {
+    #[serde(default)]
+    pub mode: ReferenceMode,
+    /// Whether share URLs may carry reference emails and phone numbers.
+    #[serde(default)]
+    pub share_contacts: bool,
+    /// Names this resume's reference contacts in local storage.
+    #[serde(default)]
+    pub storage_id: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredContact {
+    name: String,
+    email: String,
+    phone: String,
+}
+
+/// The local storage key for this resume's contacts; none before it has an id.
+fn storage_key(settings: &ReferenceSettings) -> Option<String> {
+    (!settings.storage_id.is_empty())
+        .then(|| format!("{}-{}", CONTACTS_STORAGE_KEY, settings.storage_id))
+}
+
+/// Keeps reference contact details in local storage, so they survive a
+/// reload when they are left out of the URL.
+pub fn remember_reference_contacts(references: &[Reference], settings: &ReferenceSettings) {
+    let Some(key) = storage_key(settings) else {
+        return;
+    };
+    let Some(storage) = window().and_then(|window| window.local_storage().ok().flatten()) else {
+        return;
+    };
+    let contacts = references
+        .iter()
+        .filter(|reference| !reference.name.trim().is_empty())
+        .map(|reference| StoredContact {
+            name: reference.name.trim().to_string(),
+            email: reference.email.clone(),
+            phone: reference.phone.clone(),
+        })
+        .collect::<Vec<_>>();
+    if let Ok(json) = serde_json::to_string(&contacts) {
+        let _ = storage.set_item(&key, &json);
+    }
+}
+
+/// Fills in contact details kept by `remember_reference_contacts` for
+/// references that arrived without any.
+pub fn restore_reference_contacts(references: &mut [Reference], settings: &ReferenceSettings) {
+    let Some(json) = storage_key(settings).and_then(|key| {
+        window()
+            .and_then(|window| window.local_storage().ok().flatten())
+            .and_then(|storage| storage.get_item(&key).ok().flatten())
+    }) else {
+        return;
+    };
+    if let Ok(contacts) = serde_json::from_str::<Vec<StoredContact>>(&json) {
+        fill_contacts(references, &contacts);
+    }
+}
+
+fn fill_contacts(references: &mut [Reference], contacts: &[StoredContact]) {
+    for reference in references.iter_mut() {
+        if !reference.email.is_empty() || !reference.phone.is_empty() {
+            continue;
+        }
+        if let Some(contact) = contacts
+            .iter()
+            .find(|contact| contact.name == reference.name.trim())
+        {
+            reference.email = contact.email.clone();
+            reference.phone = contact.phone.clone();
+        }
+    }
+}
+
+pub enum ReferenceField {
+    Name(String),
+    Relationship(String),
+    Company(String),
+    Email(String),
+    Phone(String),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ReferenceControllerProps {
+    pub value: Vec<Reference>,
+    pub on_change: Callback<Vec<Reference>>,
+    pub settings: ReferenceSettings,
+    pub on_settings_change: Callback<ReferenceSettings>,
+}
+
+#[function_component(ReferenceController)]
+pub fn reference_controller(props: &ReferenceControllerProps) -> Html {
+    let add_reference = {
+        let references = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_| {
+            let mut next = references.clone();
+            next.push(Reference::default());
+            on_change.emit(next);
+        })
+    };
+
+    let update_mode = {
+        let settings = props.settings.clone();
+        let on_change = props.on_settings_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            let mode = match select.value().as_str() {
+                "OnRequest" => ReferenceMode::OnRequest,
+                "Hidden" => ReferenceMode::Hidden,
+                _ => ReferenceMode::Full,
+            };
+            on_change.emit(ReferenceSettings {
+                mode,
+                ..settings.clone()
+            });
+        })
+    };
+    let mode_options = [
+        (ReferenceMode::Full, "Full details"),
+        (ReferenceMode::OnRequest, "Available upon request"),
+        (ReferenceMode::Hidden, "Hidden"),
+    ]
+    .into_iter()
+    .map(|(mode, label)| {
+        html! {
+            <option value={format!("{:?}", mode)} selected={props.settings.mode == mode}>
+                {label}
+            </option>
+        }
+    })
+    .collect::<Html>();
+    let toggle_share_contacts = {
+        let settings = props.settings.clone();
+        let on_change = props.on_settings_change.clone();
+        Callback::from(move |_| {
+            on_change.emit(ReferenceSettings {
+                share_contacts: !settings.share_contacts,
+                ..settings.clone()
+            });
+        })
+    };
+
+    let inputs = props
+        .value
+        .iter()
+        .enumerate()
+        .map(|(idx, reference)| {
+            let remove_reference = {
+                let references = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |_| {
+                    let mut next = references.clone();
+                    if idx < next.len() {
+                        next.remove(idx);
+                        on_change.emit(next);
+                    }
+                })
+            };
+
+            let name_input = make_input(
+                props,
+                idx,
+                "Name".to_string(),
+                reference.name.clone(),
+                ReferenceField::Name,
+            );
+            let relationship_input = make_input(
+                props,
+                idx,
+                "Relationship".to_string(),
+                reference.relationship.clone(),
+                ReferenceField::Relationship,
+            );
+            let company_input = make_input(
+                props,
+                idx,
+                "Company".to_string(),
+                reference.company.clone(),
+                ReferenceField::Company,
+            );
+            let email_input = make_input(
+                props,
+                idx,
+                "Email".to_string(),
+                reference.email.clone(),
+                ReferenceField::Email,
+            );
+            let phone_input = make_input(
+                props,
+                idx,
+                "Phone".to_string(),
+                reference.phone.clone(),
+                ReferenceField::Phone,
+            );
+            let update_tags = {
+                let references = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |tags: Vec<String>| {
+                    let mut next = references.clone();
+                    if let Some(reference) = next.get_mut(idx) {
+                        reference.tags = tags;
+                        on_change.emit(next);
+                    }
+                })
+            };
+            html! {
+                <>
+                    {name_input}
+                    <div class="flex">
+                        <div class="w-1/2">
+                            {relationship_input}
+                        </div>
+                        <div class="w-1/2">
+                            {company_input}
+                        </div>
+                    </div>
+                    <div class="flex">
+                        <div class="w-1/2">
+                            {email_input}
+                        </div>
+                        <div class="w-1/2">
+                            {phone_input}
+                        </div>
+                    </div>
+                    <TagsInput value={reference.tags.clone()} on_change={update_tags} />
+                    <button
+                        class={REMOVE_BUTTON_CLASS}
+                        onclick={remove_reference}
+                    >
+                        {"Remove"}
+                    </button>
+                </>
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <>
+            <div class={INPUT_SECTION_ROW_CLASS}>
+                <h5 class={INPUT_SECTION_CLASS}> {"References"} </h5>
+                <button
+                    class={ADD_BUTTON_CLASS}
+                    onclick={add_reference}
+                >
+                    {"Add New"}
+                </button>
+            </div>
+            <div class={INPUT_DIVIDER_CLASS}></div>
+            <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                <div class="relative">
+                    <select class={INPUT_CLASS} onchange={update_mode}>
+                        {mode_options}
+                    </select>
+                    <label class={LABEL_CLASS}> {"Show References As"} </label>
+                </div>
+                <label class="flex items-center gap-x-1 px-1 py-0.5 text-xs text-slate-300">
+                    <input type="checkbox"
+                           checked={props.settings.share_contacts}
+                           onclick={toggle_share_contacts}
+                    />
+                    {"Include emails and phone numbers in share links"}
+                </label>
+                if !props.settings.share_contacts {
+                    <p class="px-1 text-xs text-slate-400">
+                        {"Contact details stay in this browser and are left out of the URL."}
+                    </p>
+                }
+            </div>
+            {inputs}
+        </>
+    }
+}
+
+fn make_input<F>(
+    props: &ReferenceControllerProps,
+    idx: usize,
+    name: String,
+    value: String,
+    cons: F,
+) -> Html
+where
+    F: Fn(String) -> ReferenceField + 'static,
+{
+    let references = props.value.clone();
+    let on_change = props.on_change.clone();
+    let callback = Callback::from(move |e: InputEvent| {
+        let input: HtmlInputElement = e.target_unchecked_into();
+        let mut next = references.clone();
+        if let Some(reference) = next.get_mut(idx) {
+            match cons(input.value()) {
+                ReferenceField::Name(name) => reference.name = name,
+                ReferenceField::Relationship(relationship) => reference.relationship = relationship,
+                ReferenceField::Company(company) => reference.company = company,
+                ReferenceField::Email(email) => reference.email = email,
+                ReferenceField::Phone(phone) => reference.phone = phone,
+            }
+            on_change.emit(next);
+        }
+    });
+    html! {
+    <div class={INPUT_FIELD_WRAPPER_CLASS}>
+        <div class="relative">
+            <input type="text"
+                   id={name.clone()}
+                   oninput={callback}
+                   class={INPUT_CLASS}
+                   value={value}
+            />
+            <label for={name.clone()}
+                   class={LABEL_CLASS}>
+                {name}
+            </label>
+        </div>
+    </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub references: Vec<Reference>,
+    pub heading: String,
+    pub theme: Theme,
+    pub mode: ReferenceMode,
+}
+
+#[function_component(ReferenceViewer)]
+pub fn view_reference(props: &Props) -> Html {
+    if props.references.is_empty() || props.mode == ReferenceMode::Hidden {
+        return html! {};
+    }
+    let body = match props.mode {
+        ReferenceMode::OnRequest => html! {
+            <p class="mx-2 my-1"> {"Available upon request."} </p>
+        },
+        ReferenceMode::Full | ReferenceMode::Hidden => props
+            .references
+            .iter()
+            .map(|reference| {
+                let role = [reference.relationship.trim(), reference.company.trim()]
+                    .into_iter()
+                    .filter(|part| !part.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let contact = [reference.email.trim(), reference.phone.trim()]
+                    .into_iter()
+                    .filter(|part| !part.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" · ");
+                html! {
+                    <div class="flex justify-between">
+                        <div>
+                            <span class="font-semibold"> {&reference.name} </span>
+                            if !role.is_empty() {
+                                <span> {format!(", {}", role)} </span>
+                            }
+                        </div>
+                        <div> {contact} </div>
+                    </div>
+                }
+            })
+            .collect::<Html>(),
+    };
+    html! {
+        <>
+            <h5 class={props.theme.styles().section_header}> {props.heading.clone()} </h5>
+            <div class="mx-2 my-1 flex flex-col -space-y-1">
+                {body}
+            </div>
+        </>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::state::{assign_storage_ids, decode_state, default_seed_state, encode_state};
+
+    #[test]
+    fn share_urls_leave_out_reference_contacts_unless_allowed() {
+        let mut state = default_seed_state();
+        state.references = vec![Reference {
+            name: "Jane Doe".to_string(),
+            email: "jane@example.com".to_string(),
+            phone: "555-0100".to_string(),
+            ..Default::default()
+        }];
+
+        let shared = decode_state(&encode_state(&state).expect("state should encode"))
+            .expect("state should decode");
+        assert_eq!(shared.references[0].name, "Jane Doe");
+        assert!(shared.references[0].email.is_empty());
+        assert!(shared.references[0].phone.is_empty());
+
+        state.reference_settings.share_contacts = true;
+        let shared = decode_state(&encode_state(&state).expect("state should encode"))
+            .expect("state should decode");
+        assert_eq!(shared, state);
+    }
+
+    #[test]
+    fn restores_contacts_by_name_without_overwriting() {
+        let contact = |name: &str, email: &str| StoredContact {
+            name: name.to_string(),
+            email: email.to_string(),
+            phone: String::new(),
+        };
+        let mut references = vec![
+            Reference {
+                name: " Jane Doe ".to_string(),
+                ..Default::default()
+            },
+            Reference {
+                name: "John Roe".to_string(),
+                email: "john@work.example".to_string(),
+                ..Default::default()
+            },
+            Reference {
+                name: "Ann Lee".to_string(),
+                ..Default::default()
+            },
+        ];
+        fill_contacts(
+            &mut references,
+            &[
+                contact("Jane Doe", "jane@example.com"),
+                contact("John Roe", "john@example.com"),
+            ],
+        );
+        assert_eq!(references[0].email, "jane@example.com");
+        assert_eq!(references[1].email, "john@work.example");
+        assert!(references[2].email.is_empty());
+    }
+
+    #[test]
+    fn stored_contacts_belong_to_one_resume() {
+        let mut state = default_seed_state();
+        assert_eq!(storage_key(&state.reference_settings), None);
+        assign_storage_ids(&mut state);
+        let mut other = default_seed_state();
+        assign_storage_ids(&mut other);
+        assert!(storage_key(&state.reference_settings).is_some());
+        assert_ne!(
+            storage_key(&state.reference_settings),
+            storage_key(&other.reference_settings)
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum Section {
+    Summary,
+    Skills,
+    Languages,
+    Experiences,
+    Volunteering,
+    Projects,
+    OpenSource,
+    Education,
+    Publications,
+    Certifications,
+    References,
+}
+
+impl Section {
+    pub fn all() -> Vec<Section> {
+        vec![
+            Section::Summary,
+            Section::Skills,
+            Section::Languages,
+            Section::Experiences,
+            Section::Volunteering,
+            Section::Projects,
+            Section::OpenSource,
+            Section::Education,
+            Section::Publications,
+            Section::Certifications,
+            Section::References,
+        ]
+    }
+
+    pub fn default_heading(&self) -> &'static str {
+        match self {
+            Section::Summary => "Summary",
+            Section::Skills => "Skills",
+            Section::Languages => "Languages",
+            Section::Experiences => "Experiences",
+            Section::Volunteering => "Volunteering & Leadership",
+            Section::Projects => "Projects",
+            Section::OpenSource => "Open Source",
+            Section::Education => "Education",
+            Section::Publications => "Publications",
+            Section::Certifications => "Certifications",
+            Section::References => "References",
+        }
+    }
+}
+
+pub fn default_section_order() -> Vec<Section> {
+    Section::all()
+}
+
+/// Returns `order` with duplicates dropped and any missing sections appended,
+/// so that older states keep rendering sections added after they were saved.
+pub fn normalize_section_order(order: &[Section]) -> Vec<Section> {
+    let mut normalized: Vec<Section> = Vec::new();
+    for section in order.iter().chain(Section::all().iter()) {
+        if !normalized.contains(section) {
+            normalized.push(*section);
+        }
+    }
+    normalized
+}
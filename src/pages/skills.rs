@@ -1,23 +1,99 @@
 use serde::{Deserialize, Serialize};
-use web_sys::{HtmlInputElement, HtmlTextAreaElement};
+use web_sys::{HtmlInputElement, HtmlSelectElement};
 use yew::prelude::*;
 
 use super::html_utils::{
     ADD_BUTTON_CLASS, INPUT_CLASS, INPUT_DIVIDER_CLASS, INPUT_FIELD_WRAPPER_CLASS,
     INPUT_SECTION_CLASS, INPUT_SECTION_ROW_CLASS, LABEL_CLASS, REMOVE_BUTTON_CLASS,
-    SECTION_HEADER_CLASS, TEXTAREA_CLASS,
+    SMALL_ADD_BUTTON_CLASS, SMALL_BUTTON_CLASS, SUBSECTION_LABEL_CLASS,
 };
+use super::themes::Theme;
+use super::variants::TagsInput;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Serialize, Deserialize)]
+pub enum SkillLevel {
+    Beginner,
+    Intermediate,
+    Advanced,
+    Expert,
+}
+
+impl SkillLevel {
+    pub fn all() -> [SkillLevel; 4] {
+        [
+            SkillLevel::Beginner,
+            SkillLevel::Intermediate,
+            SkillLevel::Advanced,
+            SkillLevel::Expert,
+        ]
+    }
+
+    /// One-based rank, used to draw the level matrix.
+    fn rank(&self) -> usize {
+        *self as usize + 1
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct Skill {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<SkillLevel>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub years: Option<u8>,
+}
+
+impl Skill {
+    pub fn named(name: &str) -> Self {
+        Skill {
+            name: name.to_string(),
+            ..Skill::default()
+        }
+    }
+}
 
 #[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
 pub struct SkillCategory {
     pub category: String,
-    pub skills: String,
+    #[serde(default)]
+    pub skills: Vec<Skill>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum SkillStyle {
+    #[default]
+    Inline,
+    Chips,
+    Matrix,
+}
+
+/// Splits a comma-separated list into skills, dropping blanks and
+/// case-insensitive duplicates.
+pub fn skills_from_list(list: &str) -> Vec<Skill> {
+    let mut skills: Vec<Skill> = vec![];
+    for name in list
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+    {
+        if !skills
+            .iter()
+            .any(|skill| skill.name.eq_ignore_ascii_case(name))
+        {
+            skills.push(Skill::named(name));
+        }
+    }
+    skills
 }
 
 #[derive(Properties, PartialEq)]
 pub struct SkillControllerProps {
     pub value: Vec<SkillCategory>,
     pub on_change: Callback<Vec<SkillCategory>>,
+    pub style: SkillStyle,
+    pub on_style_change: Callback<SkillStyle>,
 }
 
 #[function_component(SkillController)]
@@ -32,6 +108,33 @@ pub fn skill_controller(props: &SkillControllerProps) -> Html {
         })
     };
 
+    let update_style = {
+        let on_style_change = props.on_style_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            let style = match select.value().as_str() {
+                "Chips" => SkillStyle::Chips,
+                "Matrix" => SkillStyle::Matrix,
+                _ => SkillStyle::Inline,
+            };
+            on_style_change.emit(style);
+        })
+    };
+    let style_options = [
+        (SkillStyle::Inline, "Inline list"),
+        (SkillStyle::Chips, "Chips"),
+        (SkillStyle::Matrix, "Level matrix"),
+    ]
+    .into_iter()
+    .map(|(style, label)| {
+        html! {
+            <option value={format!("{:?}", style)} selected={props.style == style}>
+                {label}
+            </option>
+        }
+    })
+    .collect::<Html>();
+
     let inputs = props
         .value
         .iter()
@@ -81,35 +184,30 @@ pub fn skill_controller(props: &SkillControllerProps) -> Html {
             let update_skills = {
                 let skills = props.value.clone();
                 let on_change = props.on_change.clone();
-                Callback::from(move |e: InputEvent| {
-                    let input: HtmlTextAreaElement = e.target_unchecked_into();
+                Callback::from(move |entries: Vec<Skill>| {
                     let mut next = skills.clone();
                     if let Some(skill_category) = next.get_mut(idx) {
-                        skill_category.skills = input.value();
+                        skill_category.skills = entries;
                         on_change.emit(next);
                     }
                 })
             };
-            let skills_input = html! {
-                <div class={INPUT_FIELD_WRAPPER_CLASS}>
-                <div class="relative">
-                <textarea
-                    name="description"
-                    class={TEXTAREA_CLASS}
-                    oninput={update_skills}
-                    value={skill.skills.clone()}
-                />
-                <label for={"description".to_string()}
-                       class={LABEL_CLASS} >
-                    {"Description"}
-                </label>
-                </div>
-                </div>
+            let update_tags = {
+                let skills = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |tags: Vec<String>| {
+                    let mut next = skills.clone();
+                    if let Some(skill_category) = next.get_mut(idx) {
+                        skill_category.tags = tags;
+                        on_change.emit(next);
+                    }
+                })
             };
             html! {
                 <>
                     {category_input}
-                    {skills_input}
+                    <SkillListEditor value={skill.skills.clone()} on_change={update_skills} />
+                    <TagsInput value={skill.tags.clone()} on_change={update_tags} />
                     <button
                         class={REMOVE_BUTTON_CLASS}
                         onclick={remove_skill}
@@ -132,14 +230,238 @@ pub fn skill_controller(props: &SkillControllerProps) -> Html {
                 </button>
             </div>
             <div class={INPUT_DIVIDER_CLASS}></div>
+            <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                <div class="relative">
+                    <select class={INPUT_CLASS} onchange={update_style}>
+                        {style_options}
+                    </select>
+                    <label class={LABEL_CLASS}> {"Display"} </label>
+                </div>
+            </div>
             {inputs}
         </>
     }
 }
 
+#[derive(Properties, PartialEq)]
+struct SkillListEditorProps {
+    value: Vec<Skill>,
+    on_change: Callback<Vec<Skill>>,
+}
+
+#[function_component(SkillListEditor)]
+fn skill_list_editor(props: &SkillListEditorProps) -> Html {
+    let update = |idx: usize, apply: fn(&mut Skill, String)| {
+        let skills = props.value.clone();
+        let on_change = props.on_change.clone();
+        move |value: String| {
+            let mut next = skills.clone();
+            if let Some(skill) = next.get_mut(idx) {
+                apply(skill, value);
+                on_change.emit(next);
+            }
+        }
+    };
+
+    let rows = props
+        .value
+        .iter()
+        .enumerate()
+        .map(|(idx, skill)| {
+            let update_name = {
+                let update = update(idx, |s, v| s.name = v);
+                Callback::from(move |e: InputEvent| {
+                    let input: HtmlInputElement = e.target_unchecked_into();
+                    update(input.value());
+                })
+            };
+            let update_level = {
+                let update = update(idx, |s, v| {
+                    s.level = SkillLevel::all()
+                        .into_iter()
+                        .find(|level| format!("{:?}", level) == v);
+                });
+                Callback::from(move |e: Event| {
+                    let select: HtmlSelectElement = e.target_unchecked_into();
+                    update(select.value());
+                })
+            };
+            let update_years = {
+                let update = update(idx, |s, v| s.years = v.parse().ok());
+                Callback::from(move |e: InputEvent| {
+                    let input: HtmlInputElement = e.target_unchecked_into();
+                    update(input.value());
+                })
+            };
+            let remove = {
+                let skills = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |_| {
+                    let mut next = skills.clone();
+                    if idx < next.len() {
+                        next.remove(idx);
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let level_options = SkillLevel::all()
+                .into_iter()
+                .map(|level| {
+                    html! {
+                        <option value={format!("{:?}", level)} selected={skill.level == Some(level)}>
+                            {format!("{:?}", level)}
+                        </option>
+                    }
+                })
+                .collect::<Html>();
+
+            html! {
+                <div class="grid grid-cols-[minmax(0,1fr)_8rem_4.5rem_auto] items-center gap-1">
+                    <div class="relative">
+                        <input type="text"
+                               class={INPUT_CLASS}
+                               value={skill.name.clone()}
+                               oninput={update_name}
+                        />
+                        <label class={LABEL_CLASS}> {"Skill"} </label>
+                    </div>
+                    <div class="relative">
+                        <select class={INPUT_CLASS} onchange={update_level}>
+                            <option value="" selected={skill.level.is_none()}> {"-"} </option>
+                            {level_options}
+                        </select>
+                        <label class={LABEL_CLASS}> {"Level"} </label>
+                    </div>
+                    <div class="relative">
+                        <input type="number"
+                               min="0"
+                               max="99"
+                               class={INPUT_CLASS}
+                               value={skill.years.map(|years| years.to_string()).unwrap_or_default()}
+                               oninput={update_years}
+                        />
+                        <label class={LABEL_CLASS}> {"Years"} </label>
+                    </div>
+                    <button class={SMALL_BUTTON_CLASS} onclick={remove}> {"Remove"} </button>
+                </div>
+            }
+        })
+        .collect::<Html>();
+
+    let add = {
+        let skills = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_| {
+            let mut next = skills.clone();
+            next.push(Skill::default());
+            on_change.emit(next);
+        })
+    };
+
+    html! {
+        <div class={INPUT_FIELD_WRAPPER_CLASS}>
+            <div class="flex items-center justify-between px-1 py-1">
+                <div class={SUBSECTION_LABEL_CLASS}>{"Skills"}</div>
+                <button class={SMALL_ADD_BUTTON_CLASS} onclick={add}>
+                    {"Add Skill"}
+                </button>
+            </div>
+            {rows}
+        </div>
+    }
+}
+
+fn skill_names(skills: &[Skill]) -> impl Iterator<Item = &str> {
+    skills
+        .iter()
+        .map(|skill| skill.name.trim())
+        .filter(|name| !name.is_empty())
+}
+
+/// Row and label classes, stacked for narrow columns.
+fn label_classes(
+    stacked: bool,
+    row: &'static str,
+    label: &'static str,
+) -> (&'static str, &'static str) {
+    if stacked {
+        ("flex flex-col py-0.5", "font-bold")
+    } else {
+        (row, label)
+    }
+}
+
+fn view_inline(category: &SkillCategory, stacked: bool) -> Html {
+    let (row, label) = label_classes(stacked, "flex gap-x-1", "font-bold w-1/6");
+    html! {
+        <div class={row}>
+            <span class={label}> {&category.category} </span>
+            <span class=""> {skill_names(&category.skills).collect::<Vec<_>>().join(", ")} </span>
+        </div>
+    }
+}
+
+fn view_chips(category: &SkillCategory, stacked: bool) -> Html {
+    let chips = skill_names(&category.skills)
+        .map(|name| {
+            html! {
+                <span class="rounded-full border border-slate-500 px-2 text-base"> {name} </span>
+            }
+        })
+        .collect::<Html>();
+    let (row, label) = label_classes(stacked, "flex gap-x-1 py-0.5", "font-bold w-1/6 shrink-0");
+    html! {
+        <div class={row}>
+            <span class={label}> {&category.category} </span>
+            <div class="flex flex-wrap gap-1"> {chips} </div>
+        </div>
+    }
+}
+
+fn view_matrix(category: &SkillCategory, stacked: bool) -> Html {
+    let rows = category
+        .skills
+        .iter()
+        .filter(|skill| !skill.name.trim().is_empty())
+        .map(|skill| {
+            let level = match skill.level {
+                Some(level) => format!(
+                    "{}{}",
+                    "●".repeat(level.rank()),
+                    "○".repeat(SkillLevel::all().len() - level.rank())
+                ),
+                None => String::new(),
+            };
+            let years = match skill.years {
+                Some(1) => "1 yr".to_string(),
+                Some(years) => format!("{} yrs", years),
+                None => String::new(),
+            };
+            html! {
+                <>
+                    <span> {skill.name.trim()} </span>
+                    <span class="tracking-widest"> {level} </span>
+                    <span class="text-right"> {years} </span>
+                </>
+            }
+        })
+        .collect::<Html>();
+    let (row, label) = label_classes(stacked, "flex gap-x-1 py-0.5", "font-bold w-1/6 shrink-0");
+    html! {
+        <div class={row}>
+            <span class={label}> {&category.category} </span>
+            <div class="grid grid-cols-[minmax(0,1fr)_6rem_4rem] gap-x-4 flex-1"> {rows} </div>
+        </div>
+    }
+}
+
 #[derive(Properties, PartialEq)]
 pub struct Props {
     pub skills: Vec<SkillCategory>,
+    pub heading: String,
+    pub theme: Theme,
+    #[prop_or_default]
+    pub style: SkillStyle,
 }
 
 #[function_component(SkillViewer)]
@@ -147,24 +469,42 @@ pub fn view_skill(props: &Props) -> Html {
     if props.skills.is_empty() {
         return html! {};
     }
+    let stacked = props.theme.styles().stack_labels;
     let skills = props
         .skills
         .iter()
-        .map(|skillgroup| {
-            html! {
-                <div class="flex gap-x-1">
-                    <span class="font-bold w-1/6"> {&skillgroup.category} </span>
-                    <span class=""> {&skillgroup.skills} </span>
-                </div>
-            }
+        .map(|category| match props.style {
+            SkillStyle::Inline => view_inline(category, stacked),
+            SkillStyle::Chips => view_chips(category, stacked),
+            SkillStyle::Matrix => view_matrix(category, stacked),
         })
         .collect::<Html>();
+    let list_class = match props.style {
+        SkillStyle::Inline => "mx-2 my-1 flex flex-col -space-y-1",
+        SkillStyle::Chips | SkillStyle::Matrix => "mx-2 my-1 flex flex-col",
+    };
     html! {
         <>
-            <h5 class={SECTION_HEADER_CLASS}> {"Skills"} </h5>
-            <div class="mx-2 my-1 flex flex-col -space-y-1">
+            <h5 class={props.theme.styles().section_header}> {props.heading.clone()} </h5>
+            <div class={list_class}>
                 {skills}
             </div>
         </>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_comma_lists_into_unique_skills() {
+        let skills = skills_from_list(" Rust, Python,, rust ,gRPC/Protobuf ");
+        let names = skills
+            .iter()
+            .map(|skill| skill.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["Rust", "Python", "gRPC/Protobuf"]);
+        assert!(skills.iter().all(|skill| skill.level.is_none()));
+    }
+}
@@ -0,0 +1,266 @@
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+
+use super::dates::{DateEnd, DateRange};
+use super::experiences::Experience;
+use super::html_utils::{
+    INPUT_DIVIDER_CLASS, INPUT_FIELD_WRAPPER_CLASS, INPUT_SECTION_CLASS, INPUT_SECTION_ROW_CLASS,
+};
+use super::sections::Section;
+use super::state::AppState;
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct SortSettings {
+    #[serde(default)]
+    pub newest_first: bool,
+    /// Sections that keep the order entries were entered in.
+    #[serde(default)]
+    pub manual: BTreeSet<Section>,
+}
+
+impl SortSettings {
+    fn sorts(&self, section: Section) -> bool {
+        self.newest_first && !self.manual.contains(&section)
+    }
+}
+
+/// Sections whose entries carry dates and can be sorted.
+fn dated_sections() -> Vec<Section> {
+    vec![
+        Section::Experiences,
+        Section::Volunteering,
+        Section::Projects,
+        Section::Education,
+        Section::Certifications,
+        Section::Publications,
+    ]
+}
+
+/// Orders by end date, then start date; "Present" sorts after every date.
+/// Undated ranges have no key and sort last when ordering newest first.
+fn recency(range: &DateRange) -> Option<(i32, i32)> {
+    let start = range.start.map(|date| date.months_since_epoch());
+    let end = match range.end {
+        Some(DateEnd::Present) => Some(i32::MAX),
+        Some(DateEnd::On(date)) => Some(date.months_since_epoch()),
+        None => start,
+    };
+    Some((end?, start.unwrap_or(i32::MIN)))
+}
+
+fn newest_first<T>(items: &mut [T], key: impl Fn(&T) -> Option<(i32, i32)>) {
+    items.sort_by_key(|item| std::cmp::Reverse(key(item)));
+}
+
+fn sort_experiences(experiences: &mut [Experience]) {
+    for experience in experiences.iter_mut() {
+        newest_first(&mut experience.roles, |role| recency(&role.dates));
+    }
+    newest_first(experiences, |experience| {
+        experience
+            .roles
+            .iter()
+            .filter_map(|role| recency(&role.dates))
+            .max()
+    });
+}
+
+/// Sorts dated entries newest first in place, honoring per-section opt-outs.
+pub fn apply_sorting(state: &mut AppState) {
+    let settings = state.sorting.clone();
+    if settings.sorts(Section::Experiences) {
+        sort_experiences(&mut state.experiences);
+    }
+    if settings.sorts(Section::Volunteering) {
+        sort_experiences(&mut state.volunteering);
+    }
+    if settings.sorts(Section::Projects) {
+        newest_first(&mut state.projects, |project| recency(&project.dates));
+    }
+    if settings.sorts(Section::Education) {
+        newest_first(&mut state.educations, |education| recency(&education.dates));
+    }
+    if settings.sorts(Section::Certifications) {
+        newest_first(&mut state.certifications, |certification| {
+            certification
+                .issued
+                .or(certification.expires)
+                .map(|date| (date.months_since_epoch(), 0))
+        });
+    }
+    if settings.sorts(Section::Publications) {
+        newest_first(&mut state.publications, |publication| {
+            publication.year.map(|year| (year as i32, 0))
+        });
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SortControllerProps {
+    pub value: SortSettings,
+    pub on_change: Callback<SortSettings>,
+}
+
+#[function_component(SortController)]
+pub fn sort_controller(props: &SortControllerProps) -> Html {
+    let toggle_newest_first = {
+        let settings = props.value.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_| {
+            on_change.emit(SortSettings {
+                newest_first: !settings.newest_first,
+                ..settings.clone()
+            });
+        })
+    };
+
+    let section_toggles = dated_sections()
+        .into_iter()
+        .map(|section| {
+            let toggle_manual = {
+                let settings = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |_| {
+                    let mut next = settings.clone();
+                    if !next.manual.remove(&section) {
+                        next.manual.insert(section);
+                    }
+                    on_change.emit(next);
+                })
+            };
+            html! {
+                <label class="flex items-center gap-x-1 px-1 py-0.5 text-xs text-slate-300">
+                    <input type="checkbox"
+                           checked={props.value.manual.contains(&section)}
+                           disabled={!props.value.newest_first}
+                           onclick={toggle_manual}
+                    />
+                    {format!("Manual order for {}", section.default_heading())}
+                </label>
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <>
+            <div class={INPUT_SECTION_ROW_CLASS}>
+                <h5 class={INPUT_SECTION_CLASS}> {"Sorting"} </h5>
+            </div>
+            <div class={INPUT_DIVIDER_CLASS}></div>
+            <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                <label class="flex items-center gap-x-1 px-1 py-0.5 text-xs text-slate-300">
+                    <input type="checkbox"
+                           checked={props.value.newest_first}
+                           onclick={toggle_newest_first}
+                    />
+                    {"Keep dated entries newest first"}
+                </label>
+                {section_toggles}
+            </div>
+        </>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::certifications::Certification;
+    use crate::pages::dates::parse_date;
+    use crate::pages::publications::Publication;
+    use crate::pages::state::default_seed_state;
+
+    #[test]
+    fn sorts_roles_experiences_and_education_newest_first() {
+        let mut state = default_seed_state();
+        state.experiences.reverse();
+        for experience in state.experiences.iter_mut() {
+            experience.roles.reverse();
+        }
+        state.educations.reverse();
+        state.sorting.newest_first = true;
+
+        apply_sorting(&mut state);
+        let employers = state
+            .experiences
+            .iter()
+            .map(|e| e.employer.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            employers,
+            [
+                "DRW",
+                "DRW",
+                "Georgia Institute of Technology",
+                "Uber Advanced Technology Group"
+            ]
+        );
+        assert_eq!(
+            state.experiences[1].roles[0].title,
+            "Software Developer, Senior Software Engineer"
+        );
+        assert_eq!(state.educations[0].degree, "M.S.");
+    }
+
+    #[test]
+    fn manual_sections_keep_their_order() {
+        let mut state = default_seed_state();
+        state.educations.reverse();
+        state.sorting.newest_first = true;
+        state.sorting.manual.insert(Section::Education);
+
+        apply_sorting(&mut state);
+        assert_eq!(state.educations[0].degree, "B.S.");
+    }
+
+    #[test]
+    fn sorts_certifications_by_issue_or_expiry() {
+        let certification = |name: &str, issued: &str, expires: &str| Certification {
+            name: name.to_string(),
+            issued: parse_date(issued),
+            expires: parse_date(expires),
+            ..Default::default()
+        };
+        let mut state = default_seed_state();
+        state.certifications = vec![
+            certification("Undated", "", ""),
+            certification("CKA", "2021/03", ""),
+            certification("AWS", "", "2026/05"),
+            certification("CKAD", "2023/11", "2026/11"),
+        ];
+        state.sorting.newest_first = true;
+
+        apply_sorting(&mut state);
+        let names = state
+            .certifications
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["AWS", "CKAD", "CKA", "Undated"]);
+    }
+
+    #[test]
+    fn sorts_publications_by_year_with_undated_last() {
+        let publication = |title: &str, year: Option<u16>| Publication {
+            title: title.to_string(),
+            year,
+            ..Default::default()
+        };
+        let mut state = default_seed_state();
+        state.publications = vec![
+            publication("Preprint", None),
+            publication("Swarms", Some(2019)),
+            publication("Grasping", Some(2022)),
+        ];
+        state.sorting.newest_first = true;
+
+        apply_sorting(&mut state);
+        let titles = state
+            .publications
+            .iter()
+            .map(|p| p.title.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(titles, ["Grasping", "Swarms", "Preprint"]);
+    }
+}
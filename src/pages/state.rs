@@ -3,15 +3,27 @@ use serde::{Deserialize, Serialize};
 use wasm_bindgen::JsValue;
 use web_sys::{window, UrlSearchParams};
 
-use super::basic::Basic;
+use super::basic::{Basic, ContactLink, LinkKind};
+use super::bullets::Bullet;
+use super::certifications::Certification;
+use super::dates::{DateFormat, DateRange};
 use super::education::Education;
 use super::experiences::Experience;
 use super::experiences::RolePeriod;
+use super::languages::Language;
+use super::migrations::migrate;
 use super::open_source::OpenSource;
-use super::projects::Project;
-use super::skills::SkillCategory;
+use super::projects::{Project, ProjectLink};
+use super::publications::{CitationStyle, Publication};
+use super::references::{Reference, ReferenceSettings};
+use super::sections::{default_section_order, Section};
+use super::skills::{skills_from_list, SkillCategory, SkillStyle};
+use super::sorting::SortSettings;
+use super::themes::Theme;
+use super::timeline::TimelineSettings;
+use super::variants::{parse_tags, Variant};
 
-pub const STATE_VERSION: u8 = 1;
+pub const STATE_VERSION: u8 = 9;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AppState {
@@ -20,15 +32,51 @@ pub struct AppState {
     #[serde(default)]
     pub basic: Basic,
     #[serde(default)]
+    pub summary: String,
+    #[serde(default)]
     pub educations: Vec<Education>,
     #[serde(default)]
     pub skills: Vec<SkillCategory>,
     #[serde(default)]
+    pub skill_style: SkillStyle,
+    #[serde(default)]
+    pub languages: Vec<Language>,
+    #[serde(default)]
     pub experiences: Vec<Experience>,
+    /// Community and leadership roles. Kept apart from `experiences` so they
+    /// don't count towards employment gaps or years of experience.
+    #[serde(default)]
+    pub volunteering: Vec<Experience>,
     #[serde(default)]
     pub projects: Vec<Project>,
     #[serde(default)]
     pub open_sources: Vec<OpenSource>,
+    #[serde(default)]
+    pub group_open_source: bool,
+    #[serde(default)]
+    pub certifications: Vec<Certification>,
+    #[serde(default)]
+    pub hide_expired_certifications: bool,
+    #[serde(default)]
+    pub publications: Vec<Publication>,
+    #[serde(default)]
+    pub citation_style: CitationStyle,
+    #[serde(default)]
+    pub references: Vec<Reference>,
+    #[serde(default)]
+    pub reference_settings: ReferenceSettings,
+    #[serde(default = "default_section_order")]
+    pub section_order: Vec<Section>,
+    #[serde(default)]
+    pub variants: Vec<Variant>,
+    #[serde(default)]
+    pub date_format: DateFormat,
+    #[serde(default)]
+    pub sorting: SortSettings,
+    #[serde(default)]
+    pub timeline: TimelineSettings,
+    #[serde(default)]
+    pub theme: Theme,
 }
 
 fn default_state_version() -> u8 {
@@ -42,83 +90,89 @@ pub fn default_seed_state() -> AppState {
             name: "Yilun \"Allen\" Chen".to_string(),
             email: "allenchenyilun1999@gmail.com".to_string(),
             phone: "404-409-9683".to_string(),
-            linkedin_url: Some("https://www.linkedin.com/in/yilun-allen-chen-572b71141/".to_string()),
-            github_url: Some("https://github.com/YilunAllenChen".to_string()),
+            links: vec![
+                ContactLink::new(LinkKind::LinkedIn, "https://www.linkedin.com/in/yilun-allen-chen-572b71141/"),
+                ContactLink::new(LinkKind::GitHub, "https://github.com/YilunAllenChen"),
+            ],
+            ..Default::default()
         },
+        summary: String::new(),
         educations: vec![
             Education {
                 school: "Georgia Tech".to_string(),
                 degree: "M.S.".to_string(),
                 major: "Computer Engineering".to_string(),
-                start_date: "2021/08".to_string(),
-                end_date: "2022/05".to_string(),
+                dates: DateRange::from_parts("2021/08", "2022/05"),
                 gpa: "3.87 / 4.0".to_string(),
+                ..Default::default()
             },
             Education {
                 school: "Georgia Tech".to_string(),
                 degree: "B.S.".to_string(),
                 major: "Computer Engineering".to_string(),
-                start_date: "2017/08".to_string(),
-                end_date: "2021/05".to_string(),
+                dates: DateRange::from_parts("2017/08", "2021/05"),
                 gpa: "3.86 / 4.0".to_string(),
+                ..Default::default()
             },
         ],
         skills: vec![
             SkillCategory {
                 category: "Programming".to_string(),
-                skills: "Python, Rust, Java, C++, Gleam, JavaScript, Lua, Haskell, OCaml, Go".to_string(),
+                skills: skills_from_list("Python, Rust, Java, C++, Gleam, JavaScript, Lua, Haskell, OCaml, Go"),
+                ..Default::default()
             },
             SkillCategory {
                 category: "Technologies".to_string(),
-                skills: "Git, Kafka, DeltaLake, Redis, gRPC/Protobuf, Docker, Arrow, SQL, Tailwind, ArgoCD, Neovim".to_string(),
+                skills: skills_from_list("Git, Kafka, DeltaLake, Redis, gRPC/Protobuf, Docker, Arrow, SQL, Tailwind, ArgoCD, Neovim"),
+                ..Default::default()
             },
             SkillCategory {
                 category: "The Softs".to_string(),
-                skills: "Leadership, Evidence-based Entrepreneurship, Project Management, Public Speaking".to_string(),
+                skills: skills_from_list("Leadership, Evidence-based Entrepreneurship, Project Management, Public Speaking"),
+                ..Default::default()
             },
         ],
         experiences: vec![
             Experience {
                 employer: "DRW".to_string(),
                 team: Some("Cumberland / FICC Options".to_string()),
-                title: "Head of Tools Engineering".to_string(),
-                dates: "2025/01 - Present".to_string(),
                 roles: vec![RolePeriod {
                     title: "Head of Tools Engineering".to_string(),
-                    dates: "2025/01 - Present".to_string(),
+                    dates: DateRange::parse("2025/01 - Present"),
                     location: "Chicago, IL".to_string(),
+                    ..Default::default()
                 }],
-                location: "Chicago, IL".to_string(),
-                description: [
+                bullets: [
                     "Lead, manage and grow a **global front office team of 10 engineers**, providing direct desk service and a central platform.",
                     "Standardize, document and optimize team operations, boosting engineer productivy by lowering team support burden by **80%**.",
                     "Tactically **allocate team resources** towards strategic initiatives and tech debt elimination, deprecated 95% legacy pipelines.",
                     "Push for adoption of cutting-edge technologies like ruff, uv, ty, Claude Code, Codex, n8n to continuously improve platform offerings.",
                     "Design, implement and own an **AI-native** software infrastructrue platform traders confidently leverage in production trading.",
                     "Own a versatile, ergonomic, high performance options analytics & trading tools library, used by 60+ traders and researchers globally.",
-                    "**Utilized**: Python, Java, C++, Kafka, DeltaLake, FastAPI, Plotly Dash, Kubernetes",
                 ]
-                .join("\n"),
+                .map(Bullet::from)
+                .to_vec(),
+                tech_stack: parse_tags("Python, Java, C++, Kafka, DeltaLake, FastAPI, Plotly Dash, Kubernetes"),
+                ..Default::default()
             },
             Experience {
                 employer: "DRW".to_string(),
                 team: Some("FICC Options".to_string()),
-                title: "Software Engineer, Senior Software Engineer".to_string(),
-                dates: "2022/07 - 2025/01".to_string(),
                 roles: vec![
                     RolePeriod {
                         title: "Software Developer, Senior Software Engineer".to_string(),
-                        dates: "2022/07 - 2025/01".to_string(),
+                        dates: DateRange::parse("2022/07 - 2025/01"),
                         location: "Chicago, IL".to_string(),
+                        ..Default::default()
                     },
                     RolePeriod {
                         title: "Software Developer Intern".to_string(),
-                        dates: "2021/06 - 2021/08".to_string(),
+                        dates: DateRange::parse("2021/06 - 2021/08"),
                         location: "Chicago, IL".to_string(),
+                        ..Default::default()
                     },
                 ],
-                location: "Chicago, IL".to_string(),
-                description: [
+                bullets: [
                     "Own, develop and manage the **streaming data platform** for both historical and live use cases, used by >200 systems worldwide.",
                     "Maintain a generic **stream-processing** system to ingest, clean, transform and aggregate data following the Medallion architecture.",
                     "Pioneered the implementation of a **bitemporal** binary wire transfer protocol that focuses on efficiency and the ability to time travel.",
@@ -126,151 +180,200 @@ pub fn default_seed_state() -> AppState {
                     "Spearhead the design and development of core option pricing datasets & processes with **>$10M/yr** estimated materiality.",
                     "Rearchitected of a volatility dynamics computation & visualization pipeline, making it **8x** faster and **10x** more resource efficient.",
                     "Coordinated collaboration across 3 teams and 10+ engineers to integrate exotic options pricing & risks into existing trading systems.",
-                    "**Utilized**: Python, Rust, Java, Kafka, DeltaLake, gRPC/Protobuf, Arrow, DuckDB, PostgreSQL, Presto/Trino",
                 ]
-                .join("\n"),
+                .map(Bullet::from)
+                .to_vec(),
+                tech_stack: parse_tags("Python, Rust, Java, Kafka, DeltaLake, gRPC/Protobuf, Arrow, DuckDB, PostgreSQL, Presto/Trino"),
+                ..Default::default()
             },
             Experience {
                 employer: "Uber Advanced Technology Group".to_string(),
                 team: Some("Platform Validation".to_string()),
-                title: "Software Engineering Intern".to_string(),
-                dates: "2020/05 - 2020/07".to_string(),
                 roles: vec![RolePeriod {
                     title: "Software Engineering Intern".to_string(),
-                    dates: "2020/05 - 2020/07".to_string(),
+                    dates: DateRange::parse("2020/05 - 2020/07"),
                     location: "Atlanta, GA".to_string(),
+                    ..Default::default()
                 }],
-                location: "Atlanta, GA".to_string(),
-                description: [
+                bullets: [
                     "Rearchitected a fleet orchestration system that efficiently schedule the dispatchment of autonomous vehicles for field tests",
-                    "**Utilized**: Python (asyncio), PostgreSQL",
                 ]
-                .join("\n"),
+                .map(Bullet::from)
+                .to_vec(),
+                tech_stack: parse_tags("Python (asyncio), PostgreSQL"),
+                ..Default::default()
             },
             Experience {
                 employer: "Georgia Institute of Technology".to_string(),
                 team: Some("GRITS Lab".to_string()),
-                title: "Robotics Research Assistant".to_string(),
-                dates: "2019/05 - 2022/05".to_string(),
                 roles: vec![RolePeriod {
                     title: "Robotics Research Assistant".to_string(),
-                    dates: "2019/05 - 2022/05".to_string(),
+                    dates: DateRange::parse("2019/05 - 2022/05"),
                     location: "Atlanta, GA".to_string(),
+                    ..Default::default()
                 }],
-                location: "Atlanta, GA".to_string(),
-                description: [
+                bullets: [
                     "Designed and built hardware, firmware and software for various robotics systems. Examples: [Slothbot](https://atlantabg.org/conservation-research/look-up-its-slothbot/), [Brushbot v2](https://github.com/YilunAllenChen/BrushBot)",
                     "Revamped and optimized a giga-scale urban traffic simulation testbed that backs [the GreenEVT paper](https://arxiv.org/pdf/2305.12722)",
-                    "**Utilized**: Python, ROS, Gazebo, Embedded C++, Autodesk Eagle",
-                ].join("\n"),
+                ]
+                .map(Bullet::from)
+                .to_vec(),
+                tech_stack: parse_tags("Python, ROS, Gazebo, Embedded C++, Autodesk Eagle"),
+                ..Default::default()
             }
         ],
         projects: vec![
             Project {
                 name: "Incrementars".to_string(),
                 description: "Incremental / self-adapting computing framework for Rust.".to_string(),
-                technologies: "Rust".to_string(),
-                url: Some("https://github.com/YilunAllenChen/incrementars".to_string()),
+                tech_stack: parse_tags("Rust"),
+                links: vec![ProjectLink::new("", "https://github.com/YilunAllenChen/incrementars")],
+                ..Default::default()
             },
             Project {
                 name: "Museum of Code".to_string(),
                 description: "Educational web app caputuring the beauty of programming.".to_string(),
-                technologies: "Rust".to_string(),
-                url: Some("https://yilunallenchen.github.io/museum_of_code/".to_string()),
+                tech_stack: parse_tags("Rust"),
+                links: vec![ProjectLink::new("", "https://yilunallenchen.github.io/museum_of_code/")],
+                ..Default::default()
             },
             Project {
                 name: "Rusume".to_string(),
                 description: "Real-time resume builder that was used to craft this very resume".to_string(),
-                technologies: "Rust".to_string(),
-                url: Some("https://yilunallenchen.github.io/Rusume/#/".to_string()),
+                tech_stack: parse_tags("Rust"),
+                links: vec![ProjectLink::new("", "https://yilunallenchen.github.io/Rusume/#/")],
+                ..Default::default()
             },
             Project {
                 name: "Exchange Simulator".to_string(),
                 description: "FIFO matching engine with materialized market impacts.".to_string(),
-                technologies: "Python, Rust, TypeScript".to_string(),
-                url: Some("https://tradingsim.allenchen.dev/".to_string()),
+                tech_stack: parse_tags("Python, Rust, TypeScript"),
+                links: vec![ProjectLink::new("", "https://tradingsim.allenchen.dev/")],
+                ..Default::default()
             },
             Project {
                 name: "Iterr".to_string(),
                 description:
                     "Rust-style iterator pattern in python: lazy, minimal, and type safe all the way.".to_string(),
-                technologies: "Python".to_string(),
-                url: Some("https://github.com/YilunAllenChen/iterr".to_string()),
+                tech_stack: parse_tags("Python"),
+                links: vec![ProjectLink::new("", "https://github.com/YilunAllenChen/iterr")],
+                ..Default::default()
             },
             Project {
                 name: "DaVinci Ergo Lab".to_string(),
                 description: "Ergonomic split mechanical keyboards built from the ground up. ".to_string(),
-                technologies: "Python, C++".to_string(),
-                url: Some("https://davinci-ergo-lab.com/".to_string()),
+                tech_stack: parse_tags("Python, C++"),
+                links: vec![ProjectLink::new("", "https://davinci-ergo-lab.com/")],
+                ..Default::default()
             },
             Project {
                 name: "PDE-based Anti-Aliasing".to_string(),
                 description: "Enhance computer graphics with partial differential equations. ".to_string(),
-                technologies: "Python".to_string(),
-                url: Some("https://github.com/YilunAllenChen/Dowwin_legacy/".to_string()),
+                tech_stack: parse_tags("Python"),
+                links: vec![ProjectLink::new("", "https://github.com/YilunAllenChen/Dowwin_legacy/")],
+                ..Default::default()
             },
             Project {
                 name: "SDC in GTAV".to_string(),
                 description: "Self-driving cars in Grant Theft Auto V.".to_string(),
-                technologies: "Python, C++".to_string(),
-                url: Some("https://github.com/YilunAllenChen/GTAV_SDC/".to_string()),
+                tech_stack: parse_tags("Python, C++"),
+                links: vec![ProjectLink::new("", "https://github.com/YilunAllenChen/GTAV_SDC/")],
+                ..Default::default()
             },
         ],
         open_sources: vec![
-            OpenSource {
-                name: "kafka-rust #222".to_string(),
-                url: "https://github.com/kafka-rust/kafka-rust/pull/222".to_string(),
-            },
-            OpenSource {
-                name: "kafka-rust #223".to_string(),
-                url: "https://github.com/kafka-rust/kafka-rust/pull/223".to_string(),
-            },
+            OpenSource::from_url("https://github.com/kafka-rust/kafka-rust/pull/222"),
+            OpenSource::from_url("https://github.com/kafka-rust/kafka-rust/pull/223"),
             OpenSource {
                 name: "gleam-stdlib #769".to_string(),
-                url: "https://github.com/gleam-lang/stdlib/pull/769".to_string(),
+                ..OpenSource::from_url("https://github.com/gleam-lang/stdlib/pull/769")
             },
         ],
+        group_open_source: false,
+        languages: vec![],
+        volunteering: vec![],
+        certifications: vec![],
+        hide_expired_certifications: false,
+        publications: vec![],
+        citation_style: CitationStyle::default(),
+        references: vec![],
+        reference_settings: ReferenceSettings::default(),
+        section_order: default_section_order(),
+        variants: vec![],
+        date_format: DateFormat::default(),
+        skill_style: SkillStyle::default(),
+        sorting: SortSettings::default(),
+        timeline: TimelineSettings::default(),
+        theme: Theme::default(),
+    }
+}
+
+/// A random id naming one resume's browser-only data in local storage.
+pub fn new_storage_id() -> String {
+    let mut bytes = [0u8; 8];
+    let _ = getrandom::getrandom(&mut bytes);
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Gives a resume the ids its browser-only data is stored under, so data
+/// kept for one resume is never applied to a link from someone else.
+pub fn assign_storage_ids(state: &mut AppState) {
+    if state.basic.photo.storage_id.is_empty() {
+        state.basic.photo.storage_id = new_storage_id();
+    }
+    if state.reference_settings.storage_id.is_empty() {
+        state.reference_settings.storage_id = new_storage_id();
     }
 }
 
+/// The state as it may be written to a share URL: reference contact details
+/// are dropped unless sharing them was explicitly allowed, and so is a photo
+/// kept in this browser only.
+fn shareable_state(state: &AppState) -> AppState {
+    let mut shared = state.clone();
+    if !state.reference_settings.share_contacts {
+        for reference in shared.references.iter_mut() {
+            reference.email.clear();
+            reference.phone.clear();
+        }
+    }
+    if state.basic.photo.local_only {
+        shared.basic.photo.jpeg.clear();
+    }
+    shared
+}
+
 pub fn encode_state(state: &AppState) -> Result<String, String> {
-    let json = serde_json::to_vec(state).map_err(|e| e.to_string())?;
+    let json = serde_json::to_vec(&shareable_state(state)).map_err(|e| e.to_string())?;
     Ok(URL_SAFE_NO_PAD.encode(json))
 }
 
 pub fn decode_state(encoded: &str) -> Result<AppState, String> {
     let bytes = URL_SAFE_NO_PAD.decode(encoded).map_err(|e| e.to_string())?;
-    let state: AppState = serde_json::from_slice(&bytes).map_err(|e| e.to_string())?;
-    if state.v != STATE_VERSION {
-        return Err(format!(
-            "unsupported state version: {}, expected {}",
-            state.v, STATE_VERSION
-        ));
-    }
-    Ok(state)
+    let raw: serde_json::Value = serde_json::from_slice(&bytes).map_err(|e| e.to_string())?;
+    serde_json::from_value(migrate(raw)?).map_err(|e| e.to_string())
 }
 
-pub fn load_state_from_url() -> AppState {
-    let Some(window) = window() else {
-        return default_seed_state();
-    };
-
-    let Ok(search) = window.location().search() else {
-        return default_seed_state();
-    };
-
-    let Ok(params) = UrlSearchParams::new_with_str(&search) else {
-        return default_seed_state();
-    };
+fn read_url_param(name: &str) -> Option<String> {
+    let search = window()?.location().search().ok()?;
+    UrlSearchParams::new_with_str(&search).ok()?.get(name)
+}
 
-    let Some(raw) = params.get("state") else {
+pub fn load_state_from_url() -> AppState {
+    let Some(raw) = read_url_param("state") else {
         return default_seed_state();
     };
 
     decode_state(&raw).unwrap_or_else(|_| default_seed_state())
 }
 
-pub fn write_state_to_url(state: &AppState) {
+/// Share links may carry a `variant` param naming the variant to open with.
+pub fn load_variant_from_url(state: &AppState) -> Option<usize> {
+    let name = read_url_param("variant")?;
+    state.variants.iter().position(|v| v.name == name)
+}
+
+pub fn write_state_to_url(state: &AppState, variant: Option<&str>) {
     let Some(window) = window() else {
         return;
     };
@@ -283,10 +386,18 @@ pub fn write_state_to_url(state: &AppState) {
         return;
     };
 
+    let Ok(params) = UrlSearchParams::new() else {
+        return;
+    };
+    params.append("state", &encoded_state);
+    if let Some(variant) = variant {
+        params.append("variant", variant);
+    }
+
     let location = window.location();
     let pathname = location.pathname().unwrap_or_else(|_| "/".to_string());
     let hash = location.hash().unwrap_or_default();
-    let new_url = format!("{}?state={}{}", pathname, encoded_state, hash);
+    let new_url = format!("{}?{}{}", pathname, String::from(params.to_string()), hash);
     let _ = history.replace_state_with_url(&JsValue::NULL, "", Some(&new_url));
 }
 
@@ -0,0 +1,67 @@
+use web_sys::HtmlTextAreaElement;
+use yew::prelude::*;
+
+use super::bullets::render_markdown;
+use super::html_utils::{
+    INPUT_DIVIDER_CLASS, INPUT_FIELD_WRAPPER_CLASS, INPUT_SECTION_CLASS, INPUT_SECTION_ROW_CLASS,
+    LABEL_CLASS, TEXTAREA_CLASS,
+};
+use super::themes::Theme;
+
+#[derive(Properties, PartialEq)]
+pub struct SummaryControllerProps {
+    pub value: String,
+    pub on_change: Callback<String>,
+}
+
+#[function_component(SummaryController)]
+pub fn summary_controller(props: &SummaryControllerProps) -> Html {
+    let update_summary = {
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlTextAreaElement = e.target_unchecked_into();
+            on_change.emit(input.value());
+        })
+    };
+    html! {
+        <>
+            <div class={INPUT_SECTION_ROW_CLASS}>
+                <h5 class={INPUT_SECTION_CLASS}> {"Summary"} </h5>
+            </div>
+            <div class={INPUT_DIVIDER_CLASS}></div>
+            <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                <div class="relative">
+                    <textarea
+                        class={TEXTAREA_CLASS}
+                        style="min-height: 96px"
+                        oninput={update_summary}
+                        value={props.value.clone()}
+                    />
+                    <label class={LABEL_CLASS}> {"Summary (Markdown)"} </label>
+                </div>
+            </div>
+        </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub summary: String,
+    pub heading: String,
+    pub theme: Theme,
+}
+
+#[function_component(SummaryViewer)]
+pub fn view_summary(props: &Props) -> Html {
+    if props.summary.trim().is_empty() {
+        return html! {};
+    }
+    html! {
+        <>
+            <h5 class={props.theme.styles().section_header}> {props.heading.clone()} </h5>
+            <div class="mx-2 my-1">
+                {render_markdown(&props.summary)}
+            </div>
+        </>
+    }
+}
@@ -0,0 +1,371 @@
+use std::collections::BTreeMap;
+
+use yew::prelude::*;
+
+use super::dates::{current_month, format_duration, PartialDate};
+use super::experiences::Experience;
+use super::html_utils::{
+    INPUT_DIVIDER_CLASS, INPUT_FIELD_WRAPPER_CLASS, INPUT_SECTION_CLASS, INPUT_SECTION_ROW_CLASS,
+    SMALL_ADD_BUTTON_CLASS, SUBSECTION_LABEL_CLASS,
+};
+use super::projects::Project;
+use super::skills::{Skill, SkillCategory};
+use super::timeline::span_months;
+
+const UTILIZED_PREFIX: &str = "**Utilized**:";
+
+/// Category that synced skills are added to when they aren't listed yet.
+const SYNC_CATEGORY: &str = "Technologies";
+
+/// The technology list of a legacy `**Utilized**: a, b` bullet.
+pub fn utilized_list(text: &str) -> Option<&str> {
+    text.trim().strip_prefix(UTILIZED_PREFIX).map(str::trim)
+}
+
+/// The line an experience's tech stack is rendered as.
+pub fn utilized_line(tech_stack: &[String]) -> String {
+    format!("{} {}", UTILIZED_PREFIX, tech_stack.join(", "))
+}
+
+/// Matches skills case-insensitively and ignores a trailing qualifier, so
+/// "Python (asyncio)" counts towards "Python".
+fn skill_key(name: &str) -> String {
+    let name = match name.find('(') {
+        Some(idx) => &name[..idx],
+        None => name,
+    };
+    name.trim().to_lowercase()
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct TechUsage {
+    pub name: String,
+    pub roles: usize,
+    pub projects: usize,
+    /// Months covered by roles using it, counting overlapping roles once.
+    pub months: i32,
+}
+
+#[derive(Default)]
+struct Tally {
+    name: String,
+    roles: usize,
+    projects: usize,
+    spans: Vec<(i32, i32)>,
+}
+
+fn tally_for<'a>(usage: &'a mut BTreeMap<String, Tally>, name: &str) -> &'a mut Tally {
+    let tally = usage.entry(skill_key(name)).or_default();
+    if tally.name.is_empty() {
+        tally.name = name.trim().to_string();
+    }
+    tally
+}
+
+fn covered_months(mut spans: Vec<(i32, i32)>) -> i32 {
+    spans.sort();
+    let mut months = 0;
+    let mut covered_until = i32::MIN;
+    for (start, end) in spans {
+        let start = start.max(covered_until);
+        if end > start {
+            months += end - start;
+            covered_until = end;
+        }
+    }
+    months
+}
+
+/// Totals every tech stack entry across experiences and projects, most
+/// used first.
+pub fn aggregate_tech_stack(
+    experiences: &[Experience],
+    projects: &[Project],
+    today: PartialDate,
+) -> Vec<TechUsage> {
+    let mut usage: BTreeMap<String, Tally> = BTreeMap::new();
+    for experience in experiences {
+        let spans = experience
+            .roles
+            .iter()
+            .filter_map(|role| span_months(&role.dates, today))
+            .collect::<Vec<_>>();
+        for name in unique_names(&experience.tech_stack) {
+            let tally = tally_for(&mut usage, name);
+            tally.roles += experience.roles.len();
+            tally.spans.extend(spans.iter().copied());
+        }
+    }
+    for project in projects {
+        for name in unique_names(&project.tech_stack) {
+            tally_for(&mut usage, name).projects += 1;
+        }
+    }
+
+    let mut totals = usage
+        .into_values()
+        .map(|tally| TechUsage {
+            name: tally.name,
+            roles: tally.roles,
+            projects: tally.projects,
+            months: covered_months(tally.spans),
+        })
+        .collect::<Vec<_>>();
+    totals.sort_by(|a, b| {
+        (b.months, b.roles, b.projects)
+            .cmp(&(a.months, a.roles, a.projects))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    totals
+}
+
+fn unique_names(names: &[String]) -> Vec<&str> {
+    let mut seen = vec![];
+    names
+        .iter()
+        .map(|name| name.trim())
+        .filter(|name| !name.is_empty())
+        .filter(|name| {
+            let key = skill_key(name);
+            let fresh = !seen.contains(&key);
+            seen.push(key);
+            fresh
+        })
+        .collect()
+}
+
+/// Skills listed in the Skills section that no experience or project uses.
+pub fn unused_skills(skills: &[SkillCategory], usage: &[TechUsage]) -> Vec<String> {
+    skills
+        .iter()
+        .flat_map(|category| category.skills.iter())
+        .filter(|skill| !skill.name.trim().is_empty())
+        .filter(|skill| {
+            let key = skill_key(&skill.name);
+            !usage.iter().any(|used| skill_key(&used.name) == key)
+        })
+        .map(|skill| skill.name.clone())
+        .collect()
+}
+
+/// Fills in years for listed skills and adds used skills that aren't listed
+/// yet to the "Technologies" category.
+pub fn sync_skills(skills: &[SkillCategory], usage: &[TechUsage]) -> Vec<SkillCategory> {
+    let mut next = skills.to_vec();
+    for used in usage {
+        let key = skill_key(&used.name);
+        let years = match (used.months + 6) / 12 {
+            0 => None,
+            years => Some(years.min(u8::MAX as i32) as u8),
+        };
+        let listed = next
+            .iter_mut()
+            .flat_map(|category| category.skills.iter_mut())
+            .find(|skill| skill_key(&skill.name) == key);
+        match listed {
+            Some(skill) => {
+                if years.is_some() {
+                    skill.years = years;
+                }
+            }
+            None => {
+                let category = match next
+                    .iter()
+                    .position(|category| category.category == SYNC_CATEGORY)
+                {
+                    Some(idx) => &mut next[idx],
+                    None => {
+                        next.push(SkillCategory {
+                            category: SYNC_CATEGORY.to_string(),
+                            ..Default::default()
+                        });
+                        next.last_mut().expect("category was just pushed")
+                    }
+                };
+                category.skills.push(Skill {
+                    years,
+                    ..Skill::named(&used.name)
+                });
+            }
+        }
+    }
+    next
+}
+
+#[derive(Properties, PartialEq)]
+pub struct TechStackPanelProps {
+    pub experiences: Vec<Experience>,
+    pub projects: Vec<Project>,
+    pub skills: Vec<SkillCategory>,
+    pub on_skills_change: Callback<Vec<SkillCategory>>,
+}
+
+#[function_component(TechStackPanel)]
+pub fn tech_stack_panel(props: &TechStackPanelProps) -> Html {
+    let usage = aggregate_tech_stack(&props.experiences, &props.projects, current_month());
+    let unused = unused_skills(&props.skills, &usage);
+
+    let sync = {
+        let skills = props.skills.clone();
+        let usage = usage.clone();
+        let on_change = props.on_skills_change.clone();
+        Callback::from(move |_| on_change.emit(sync_skills(&skills, &usage)))
+    };
+
+    let rows = usage
+        .iter()
+        .map(|used| {
+            let mut counts = vec![];
+            if used.roles > 0 {
+                counts.push(match used.roles {
+                    1 => "1 role".to_string(),
+                    roles => format!("{} roles", roles),
+                });
+            }
+            if used.months > 0 {
+                counts.push(format_duration(used.months));
+            }
+            if used.projects > 0 {
+                counts.push(match used.projects {
+                    1 => "1 project".to_string(),
+                    projects => format!("{} projects", projects),
+                });
+            }
+            html! {
+                <>
+                    <span> {used.name.clone()} </span>
+                    <span class="text-right text-slate-400"> {counts.join(" · ")} </span>
+                </>
+            }
+        })
+        .collect::<Html>();
+
+    let unused = if unused.is_empty() {
+        html! {}
+    } else {
+        html! {
+            <>
+                <div class={SUBSECTION_LABEL_CLASS}> {"Listed in Skills but never used"} </div>
+                <p class="px-1 py-0.5 text-xs text-amber-300"> {unused.join(", ")} </p>
+            </>
+        }
+    };
+
+    html! {
+        <>
+            <div class={INPUT_SECTION_ROW_CLASS}>
+                <h5 class={INPUT_SECTION_CLASS}> {"Tech Stack"} </h5>
+            </div>
+            <div class={INPUT_DIVIDER_CLASS}></div>
+            <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                <div class="flex items-center justify-between px-1 py-1">
+                    <div class={SUBSECTION_LABEL_CLASS}> {"Usage"} </div>
+                    <button
+                        class={SMALL_ADD_BUTTON_CLASS}
+                        title="Adds missing skills and updates years of experience"
+                        onclick={sync}
+                    >
+                        {"Sync to Skills"}
+                    </button>
+                </div>
+                <div class="grid grid-cols-[minmax(0,1fr)_auto] gap-x-2 px-1 text-xs text-slate-300">
+                    {rows}
+                </div>
+                {unused}
+            </div>
+        </>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::dates::DateRange;
+    use crate::pages::experiences::RolePeriod;
+    use crate::pages::skills::skills_from_list;
+
+    fn today() -> PartialDate {
+        PartialDate {
+            year: 2026,
+            month: 10,
+            day: None,
+        }
+    }
+
+    fn experience(dates: &[&str], tech_stack: &[&str]) -> Experience {
+        Experience {
+            roles: dates
+                .iter()
+                .map(|dates| RolePeriod {
+                    dates: DateRange::parse(dates),
+                    ..Default::default()
+                })
+                .collect(),
+            tech_stack: tech_stack.iter().map(|s| s.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn aggregates_roles_months_and_projects() {
+        let experiences = vec![
+            experience(&["2022/01 - 2024/01"], &["Python", "Rust"]),
+            experience(
+                &["2023/01 - 2023/07", "2020/01 - 2020/04"],
+                &["python (asyncio)"],
+            ),
+        ];
+        let projects = vec![Project {
+            tech_stack: vec!["Rust".to_string()],
+            ..Default::default()
+        }];
+
+        let usage = aggregate_tech_stack(&experiences, &projects, today());
+        assert_eq!(
+            usage,
+            [
+                TechUsage {
+                    name: "Python".to_string(),
+                    roles: 3,
+                    projects: 0,
+                    months: 29,
+                },
+                TechUsage {
+                    name: "Rust".to_string(),
+                    roles: 1,
+                    projects: 1,
+                    months: 25,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn syncs_usage_into_skills_and_flags_unused() {
+        let skills = vec![SkillCategory {
+            category: "Languages".to_string(),
+            skills: skills_from_list("Python, Gleam"),
+            ..Default::default()
+        }];
+        let usage = aggregate_tech_stack(
+            &[experience(&["2022/01 - 2024/01"], &["Python", "Kafka"])],
+            &[],
+            today(),
+        );
+
+        assert_eq!(unused_skills(&skills, &usage), ["Gleam"]);
+        let synced = sync_skills(&skills, &usage);
+        assert_eq!(synced[0].skills[0].years, Some(2));
+        assert_eq!(synced[1].category, SYNC_CATEGORY);
+        assert_eq!(synced[1].skills[0].name, "Kafka");
+    }
+
+    #[test]
+    fn reads_legacy_utilized_lines() {
+        assert_eq!(
+            utilized_list("**Utilized**: Python, Rust"),
+            Some("Python, Rust")
+        );
+        assert_eq!(utilized_list("Built a thing"), None);
+    }
+}
@@ -0,0 +1,1276 @@
+use web_sys::HtmlTextAreaElement;
+use yew::prelude::*;
+
+use super::basic::{ContactLink, LinkKind};
+use super::bullets::Bullet;
+use super::certifications::Certification;
+use super::dates::{current_month, is_present, month_from_name, parse_date, DateFormat, DateRange};
+use super::education::Education;
+use super::experiences::{Experience, RolePeriod};
+use super::html_utils::{
+    INPUT_DIVIDER_CLASS, INPUT_FIELD_WRAPPER_CLASS, INPUT_SECTION_CLASS, INPUT_SECTION_ROW_CLASS,
+    LABEL_CLASS, SMALL_ADD_BUTTON_CLASS, SMALL_BUTTON_CLASS, TEXTAREA_CLASS,
+};
+use super::projects::{Project, ProjectLink};
+use super::sections::Section;
+use super::skills::{skills_from_list, SkillCategory};
+use super::state::AppState;
+
+const TITLE_WORDS: [&str; 24] = [
+    "engineer",
+    "developer",
+    "programmer",
+    "manager",
+    "intern",
+    "analyst",
+    "designer",
+    "lead",
+    "director",
+    "consultant",
+    "scientist",
+    "architect",
+    "specialist",
+    "coordinator",
+    "assistant",
+    "officer",
+    "head",
+    "president",
+    "founder",
+    "administrator",
+    "researcher",
+    "associate",
+    "volunteer",
+    "teacher",
+];
+
+const SCHOOL_WORDS: [&str; 7] = [
+    "university",
+    "college",
+    "school",
+    "institute",
+    "academy",
+    "polytechnic",
+    "université",
+];
+
+const DEGREE_WORDS: [&str; 16] = [
+    "bachelor",
+    "master",
+    "doctor",
+    "associate",
+    "diploma",
+    "b.s",
+    "bs",
+    "bsc",
+    "b.sc",
+    "b.a",
+    "ba",
+    "m.s",
+    "ms",
+    "msc",
+    "mba",
+    "phd",
+];
+
+/// A field of the draft the parser was unsure about.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Doubt {
+    /// `None` for the name and contact lines at the top.
+    pub section: Option<Section>,
+    pub index: usize,
+    /// e.g. "Employer", or "Title (role 2)".
+    pub field: String,
+    pub reason: &'static str,
+}
+
+/// A draft resume built from pasted text, ready for review.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TextImport {
+    pub draft: AppState,
+    /// The sections filled from the text, in the order they appeared.
+    pub found: Vec<Section>,
+    /// Headings whose content had nowhere to go.
+    pub skipped: Vec<String>,
+    pub doubts: Vec<Doubt>,
+}
+
+impl TextImport {
+    pub fn doubt(&self, section: Option<Section>, index: usize, field: &str) -> Option<&Doubt> {
+        self.doubts
+            .iter()
+            .find(|doubt| doubt.section == section && doubt.index == index && doubt.field == field)
+    }
+}
+
+enum Block {
+    Top,
+    Section(Section),
+    Skipped(String),
+}
+
+fn heading(line: &str) -> Option<Block> {
+    let name = line
+        .trim()
+        .trim_end_matches(':')
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    let section = match name.to_lowercase().replace(" and ", " & ").as_str() {
+        "summary"
+        | "professional summary"
+        | "profile"
+        | "about"
+        | "about me"
+        | "objective"
+        | "career objective" => Section::Summary,
+        "experience"
+        | "work experience"
+        | "professional experience"
+        | "relevant experience"
+        | "employment"
+        | "employment history"
+        | "work history"
+        | "career history" => Section::Experiences,
+        "volunteering"
+        | "volunteer experience"
+        | "volunteer work"
+        | "leadership"
+        | "leadership experience"
+        | "leadership & volunteering"
+        | "volunteering & leadership" => Section::Volunteering,
+        "education" | "academic background" | "education & training" => Section::Education,
+        "skills" | "technical skills" | "core skills" | "key skills" | "core competencies"
+        | "technologies" | "skills & tools" => Section::Skills,
+        "projects" | "personal projects" | "selected projects" | "side projects" => {
+            Section::Projects
+        }
+        "certifications" | "certificates" | "licenses & certifications" => Section::Certifications,
+        "languages"
+        | "publications"
+        | "references"
+        | "awards"
+        | "honors"
+        | "honors & awards"
+        | "achievements"
+        | "interests"
+        | "hobbies"
+        | "activities"
+        | "courses"
+        | "training"
+        | "patents"
+        | "talks"
+        | "memberships"
+        | "additional information" => return Some(Block::Skipped(name)),
+        _ => return None,
+    };
+    Some(Block::Section(section))
+}
+
+/// Splits the text at recognized headings; the lines before the first one
+/// are the name and contact details.
+fn split_blocks(text: &str) -> Vec<(Block, Vec<&str>)> {
+    let mut blocks = vec![(Block::Top, vec![])];
+    for line in text.lines() {
+        let block = match strip_bullet(line) {
+            Some(_) => None,
+            None => heading(line),
+        };
+        match block {
+            Some(block) => blocks.push((block, vec![])),
+            None => {
+                if let Some((_, lines)) = blocks.last_mut() {
+                    lines.push(line);
+                }
+            }
+        }
+    }
+    blocks
+}
+
+/// The text after a bullet glyph (•, -, * and friends), if the line has one.
+fn strip_bullet(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    let mut chars = trimmed.chars();
+    let glyph = chars.next()?;
+    let rest = chars.as_str();
+    match glyph {
+        '•' | '▪' | '◦' | '●' | '○' | '■' | '►' | '➢' => Some(rest.trim()),
+        '-' | '*' | '–' | '·' if rest.starts_with(char::is_whitespace) => Some(rest.trim()),
+        _ => None,
+    }
+}
+
+/// Dates found in a line. Ranges given only as years are kept as text.
+struct FoundDates {
+    range: DateRange,
+    exact: bool,
+}
+
+#[derive(PartialEq)]
+enum DateWord {
+    Anchor,
+    Part,
+    Separator,
+    Other,
+}
+
+fn is_year(word: &str) -> bool {
+    word.len() == 4
+        && word
+            .parse::<u16>()
+            .is_ok_and(|year| (1950..=2100).contains(&year))
+}
+
+fn clean_word(word: &str) -> &str {
+    word.trim_matches(|c: char| "()[],;|".contains(c))
+}
+
+fn date_word(word: &str) -> DateWord {
+    let word = clean_word(word);
+    if ["-", "–", "—", "to", "until"].contains(&word.to_lowercase().as_str()) {
+        DateWord::Separator
+    } else if is_year(word) || parse_date(word).is_some() {
+        DateWord::Anchor
+    } else if is_present(word) || month_from_name(word).is_some() {
+        DateWord::Part
+    } else {
+        DateWord::Other
+    }
+}
+
+/// Pulls the first date or date range out of a line, returning the rest.
+fn split_dates(line: &str) -> (Option<FoundDates>, String) {
+    let spaced = line.replace('–', " – ").replace('—', " — ");
+    let mut words = vec![];
+    for word in spaced.split_whitespace() {
+        // "2019-2021" and "2019-Present"
+        match clean_word(word).split_once('-') {
+            Some((from, to)) if is_year(from) && (is_year(to) || is_present(to)) => {
+                words.extend([from, "-", to]);
+            }
+            _ => words.push(word),
+        }
+    }
+    let kinds = words.iter().map(|word| date_word(word)).collect::<Vec<_>>();
+
+    let mut start = 0;
+    while start < words.len() {
+        if kinds[start] == DateWord::Other {
+            start += 1;
+            continue;
+        }
+        let mut end = start;
+        while end < words.len() && kinds[end] != DateWord::Other {
+            end += 1;
+        }
+        let mut from = start;
+        let mut to = end;
+        while from < to && kinds[from] == DateWord::Separator {
+            from += 1;
+        }
+        while to > from && kinds[to - 1] == DateWord::Separator {
+            to -= 1;
+        }
+        if kinds[from..to].contains(&DateWord::Anchor) {
+            let text = words[from..to]
+                .iter()
+                .map(|word| clean_word(word))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let range = DateRange::parse(&text);
+            let exact = range.text.is_empty();
+            let rest = words[..start]
+                .iter()
+                .chain(&words[end..])
+                .copied()
+                .collect::<Vec<_>>()
+                .join(" ");
+            return (Some(FoundDates { range, exact }), trim_separators(&rest));
+        }
+        start = end;
+    }
+    (None, trim_separators(line))
+}
+
+fn trim_separators(text: &str) -> String {
+    text.trim_matches(|c: char| c.is_whitespace() || "|,;-–—·•()".contains(c))
+        .to_string()
+}
+
+/// Splits a header line such as "Acme Corp | Staff Engineer | Berlin".
+fn split_parts(line: &str) -> Vec<String> {
+    let mut parts = vec![line.to_string()];
+    for separator in [" | ", "|", " — ", " – ", " - ", " · ", " • ", "\t"] {
+        parts = parts
+            .iter()
+            .flat_map(|part| part.split(separator))
+            .map(trim_separators)
+            .filter(|part| !part.is_empty())
+            .collect();
+    }
+    parts
+}
+
+fn has_word(text: &str, words: &[&str]) -> bool {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric() && c != '.')
+        .map(|word| word.trim_end_matches('.'))
+        .any(|word| {
+            words
+                .iter()
+                .any(|known| known.trim_end_matches('.') == word)
+        })
+}
+
+fn looks_like_location(part: &str) -> bool {
+    let lower = part.to_lowercase();
+    if ["remote", "hybrid", "on-site", "onsite"]
+        .iter()
+        .any(|word| lower.contains(word))
+    {
+        return true;
+    }
+    // "Berlin, Germany" or "Austin, TX"
+    match part.rsplit_once(", ") {
+        Some((city, region)) => {
+            !has_word(part, &TITLE_WORDS)
+                && city.split_whitespace().count() <= 3
+                && region.split_whitespace().count() <= 2
+                && region.chars().next().is_some_and(char::is_uppercase)
+                && !["inc", "inc.", "llc", "ltd", "ltd.", "gmbh", "co."]
+                    .contains(&region.to_lowercase().as_str())
+        }
+        None => false,
+    }
+}
+
+/// An entry under a heading: header lines, their dates, and bullets.
+#[derive(Default)]
+struct Entry {
+    header: Vec<String>,
+    dates: Option<FoundDates>,
+    bullets: Vec<String>,
+}
+
+impl Entry {
+    fn parts(&self) -> Vec<String> {
+        self.header
+            .iter()
+            .flat_map(|line| split_parts(line))
+            .collect()
+    }
+
+    fn bullets(&self) -> Vec<Bullet> {
+        self.bullets
+            .iter()
+            .map(|text| Bullet::from(text.as_str()))
+            .collect()
+    }
+}
+
+fn split_entries(lines: &[&str]) -> Vec<Entry> {
+    let mut entries: Vec<Entry> = vec![];
+    let mut after_blank = false;
+    for line in lines {
+        if line.trim().is_empty() {
+            after_blank = true;
+            continue;
+        }
+        let current = entries.last_mut();
+        if let Some(text) = strip_bullet(line) {
+            match current {
+                Some(entry) => entry.bullets.push(text.to_string()),
+                None => entries.push(Entry {
+                    bullets: vec![text.to_string()],
+                    ..Entry::default()
+                }),
+            }
+            after_blank = false;
+            continue;
+        }
+        // A wrapped bullet continues on an indented or lowercase line.
+        let wrapped = line.starts_with(char::is_whitespace)
+            || line.trim_start().starts_with(char::is_lowercase);
+        let (dates, rest) = split_dates(line);
+        match current {
+            Some(entry) if !entry.bullets.is_empty() && wrapped && !after_blank => {
+                if let Some(last) = entry.bullets.last_mut() {
+                    last.push(' ');
+                    last.push_str(line.trim());
+                }
+            }
+            Some(entry)
+                if entry.bullets.is_empty()
+                    && !after_blank
+                    && entry.header.len() < 3
+                    && !(entry.dates.is_some() && dates.is_some()) =>
+            {
+                if !rest.is_empty() {
+                    entry.header.push(rest);
+                }
+                if dates.is_some() {
+                    entry.dates = dates;
+                }
+            }
+            // Prose under a complete header is a description, not a new entry.
+            Some(entry)
+                if entry.dates.is_some()
+                    && dates.is_none()
+                    && (line.trim().ends_with('.') || line.split_whitespace().count() >= 8) =>
+            {
+                entry.bullets.push(line.trim().to_string());
+            }
+            _ => entries.push(Entry {
+                header: if rest.is_empty() { vec![] } else { vec![rest] },
+                dates,
+                ..Entry::default()
+            }),
+        }
+        after_blank = false;
+    }
+    entries
+}
+
+/// Collects doubts for one draft entry.
+struct Doubts<'a> {
+    doubts: &'a mut Vec<Doubt>,
+    section: Option<Section>,
+    index: usize,
+}
+
+impl Doubts<'_> {
+    fn add(&mut self, field: String, reason: &'static str) {
+        self.doubts.push(Doubt {
+            section: self.section,
+            index: self.index,
+            field,
+            reason,
+        });
+    }
+
+    fn dates(&mut self, field: String, dates: &Option<FoundDates>) {
+        match dates {
+            None => self.add(field, "No dates found"),
+            Some(FoundDates { exact: false, .. }) => {
+                self.add(field, "Only years were recognized; kept as text")
+            }
+            Some(_) => {}
+        }
+    }
+}
+
+fn role_field(field: &str, role: usize) -> String {
+    match role {
+        0 => field.to_string(),
+        role => format!("{} (role {})", field, role + 1),
+    }
+}
+
+/// Reads "Title at Employer", "Employer | Title" and similar headers.
+fn title_and_employer(parts: &[String]) -> (String, String, String, bool) {
+    let (locations, mut parts): (Vec<_>, Vec<_>) = parts
+        .iter()
+        .cloned()
+        .partition(|part| looks_like_location(part));
+    let location = locations.join(", ");
+    if parts.len() == 1 {
+        if let Some((title, employer)) = parts[0].split_once(" at ") {
+            return (title.trim().into(), employer.trim().into(), location, true);
+        }
+        if let Some((first, second)) = parts[0].clone().split_once(", ") {
+            parts = vec![first.to_string(), second.to_string()];
+        }
+    }
+    let titled = parts.iter().position(|part| has_word(part, &TITLE_WORDS));
+    match titled {
+        Some(idx) if parts.len() >= 2 => {
+            let title = parts.remove(idx);
+            (title, parts.remove(0), location, true)
+        }
+        _ => {
+            let mut parts = parts.into_iter();
+            let employer = parts.next().unwrap_or_default();
+            let title = parts.next().unwrap_or_default();
+            (title, employer, location, false)
+        }
+    }
+}
+
+fn parse_experiences(lines: &[&str], section: Section, doubts: &mut Vec<Doubt>) -> Vec<Experience> {
+    let mut experiences: Vec<Experience> = vec![];
+    for entry in split_entries(lines) {
+        let parts = entry.parts();
+        // A title line with dates under the previous employer is another role.
+        let another_role = !experiences.is_empty()
+            && parts.len() == 1
+            && has_word(&parts[0], &TITLE_WORDS)
+            && entry.dates.is_some();
+        let role_idx = if another_role {
+            experiences
+                .last()
+                .map_or(0, |experience| experience.roles.len())
+        } else {
+            0
+        };
+        let index = if another_role {
+            experiences.len() - 1
+        } else {
+            experiences.len()
+        };
+        let mut doubts = Doubts {
+            doubts,
+            section: Some(section),
+            index,
+        };
+        let (title, employer, location, sure) = if another_role {
+            (parts[0].clone(), String::new(), String::new(), true)
+        } else {
+            title_and_employer(&parts)
+        };
+        if !sure || title.is_empty() {
+            doubts.add(
+                role_field("Title", role_idx),
+                "Couldn't tell the title apart",
+            );
+        }
+        if !another_role && (!sure || employer.is_empty()) {
+            doubts.add("Employer".to_string(), "Couldn't tell the employer apart");
+        }
+        doubts.dates(role_field("Dates", role_idx), &entry.dates);
+
+        let role = RolePeriod {
+            title,
+            dates: entry
+                .dates
+                .as_ref()
+                .map(|dates| dates.range.clone())
+                .unwrap_or_default(),
+            location,
+            bullets: entry.bullets(),
+        };
+        match experiences.last_mut() {
+            Some(last) if another_role => last.roles.push(role),
+            _ => experiences.push(Experience {
+                employer,
+                roles: vec![role],
+                ..Experience::default()
+            }),
+        }
+    }
+    experiences
+}
+
+fn parse_educations(lines: &[&str], doubts: &mut Vec<Doubt>) -> Vec<Education> {
+    split_entries(lines)
+        .into_iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let mut doubts = Doubts {
+                doubts,
+                section: Some(Section::Education),
+                index,
+            };
+            let mut parts = entry.parts();
+            let mut education = Education {
+                dates: entry
+                    .dates
+                    .as_ref()
+                    .map(|dates| dates.range.clone())
+                    .unwrap_or_default(),
+                bullets: entry.bullets(),
+                ..Education::default()
+            };
+            if let Some(idx) = parts.iter().position(|part| part.contains("GPA")) {
+                let gpa = parts.remove(idx);
+                education.gpa = trim_separators(&gpa.replace("GPA", "").replace(':', ""));
+            }
+            parts.retain(|part| !looks_like_location(part));
+            let school = parts.iter().position(|part| has_word(part, &SCHOOL_WORDS));
+            if let Some(idx) = school {
+                education.school = parts.remove(idx);
+            }
+            let degree = parts.iter().position(|part| has_word(part, &DEGREE_WORDS));
+            if let Some(idx) = degree {
+                let degree = parts.remove(idx);
+                match degree.split_once(" in ") {
+                    Some((degree, major)) => {
+                        education.degree = degree.trim().to_string();
+                        education.major = major.trim().to_string();
+                    }
+                    None => education.degree = degree,
+                }
+            }
+            let mut rest = parts.into_iter();
+            if school.is_none() {
+                education.school = rest.next().unwrap_or_default();
+                doubts.add("School".to_string(), "No university or college named");
+            }
+            if degree.is_none() {
+                education.degree = rest.next().unwrap_or_default();
+                doubts.add("Degree".to_string(), "No degree recognized");
+            }
+            doubts.dates("Dates".to_string(), &entry.dates);
+            education
+        })
+        .collect()
+}
+
+fn parse_projects(lines: &[&str], doubts: &mut Vec<Doubt>) -> Vec<Project> {
+    split_entries(lines)
+        .into_iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let (urls, parts): (Vec<_>, Vec<_>) = entry
+                .parts()
+                .into_iter()
+                .partition(|part| link_kind(part).is_some());
+            let mut parts = parts.into_iter();
+            let name = parts.next().unwrap_or_default();
+            if name.is_empty() {
+                doubts.push(Doubt {
+                    section: Some(Section::Projects),
+                    index,
+                    field: "Name".to_string(),
+                    reason: "No project name found",
+                });
+            }
+            Project {
+                name,
+                description: parts.collect::<Vec<_>>().join(", "),
+                dates: entry
+                    .dates
+                    .as_ref()
+                    .map(|dates| dates.range.clone())
+                    .unwrap_or_default(),
+                bullets: entry.bullets(),
+                links: urls
+                    .iter()
+                    .map(|url| ProjectLink::new("", &with_scheme(url)))
+                    .collect(),
+                ..Project::default()
+            }
+        })
+        .collect()
+}
+
+fn parse_certifications(lines: &[&str], doubts: &mut Vec<Doubt>) -> Vec<Certification> {
+    lines
+        .iter()
+        .map(|line| strip_bullet(line).unwrap_or(line))
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(index, line)| {
+            let (dates, rest) = split_dates(line);
+            let mut parts = split_parts(&rest).into_iter();
+            let issued = dates.as_ref().and_then(|dates| dates.range.start);
+            if dates.is_some() && issued.is_none() {
+                doubts.push(Doubt {
+                    section: Some(Section::Certifications),
+                    index,
+                    field: "Issued".to_string(),
+                    reason: "Only the year was recognized",
+                });
+            }
+            Certification {
+                name: parts.next().unwrap_or_default(),
+                issuer: parts.collect::<Vec<_>>().join(", "),
+                issued,
+                ..Certification::default()
+            }
+        })
+        .collect()
+}
+
+fn parse_skills(lines: &[&str]) -> Vec<SkillCategory> {
+    let mut categories: Vec<SkillCategory> = vec![];
+    let mut loose = vec![];
+    for line in lines {
+        let line = strip_bullet(line).unwrap_or(line).trim();
+        let list = |text: &str| skills_from_list(&text.replace(['|', ';', '•', '·'], ","));
+        match line.split_once(':') {
+            Some((category, skills)) if !category.contains(',') => categories.push(SkillCategory {
+                category: category.trim().to_string(),
+                skills: list(skills),
+                ..SkillCategory::default()
+            }),
+            _ => loose.extend(list(line)),
+        }
+    }
+    if !loose.is_empty() {
+        categories.push(SkillCategory {
+            category: "Skills".to_string(),
+            skills: loose,
+            ..SkillCategory::default()
+        });
+    }
+    categories
+}
+
+fn parse_summary(lines: &[&str]) -> String {
+    let mut paragraphs: Vec<String> = vec![];
+    let mut current: Vec<String> = vec![];
+    for line in lines {
+        if let Some(text) = strip_bullet(line) {
+            current.push(format!("\n- {}", text));
+        } else if line.trim().is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(current.join(" ").trim().to_string());
+                current.clear();
+            }
+        } else {
+            current.push(line.trim().to_string());
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current.join(" ").trim().to_string());
+    }
+    paragraphs.join("\n\n").replace(" \n- ", "\n- ")
+}
+
+fn link_kind(token: &str) -> Option<LinkKind> {
+    let lower = token.to_lowercase();
+    if lower.contains(' ')
+        || !(lower.contains("://")
+            || lower.starts_with("www.")
+            || lower.contains(".com/")
+            || lower.contains(".org/")
+            || lower.contains(".io/")
+            || lower.contains(".dev"))
+    {
+        return None;
+    }
+    Some(if lower.contains("linkedin.com") {
+        LinkKind::LinkedIn
+    } else if lower.contains("github.com") {
+        LinkKind::GitHub
+    } else if lower.contains("scholar.google") {
+        LinkKind::GoogleScholar
+    } else if lower.contains("orcid.org") {
+        LinkKind::Orcid
+    } else {
+        LinkKind::Website
+    })
+}
+
+fn with_scheme(url: &str) -> String {
+    if url.contains("://") {
+        url.to_string()
+    } else {
+        format!("https://{}", url)
+    }
+}
+
+fn is_phone(token: &str) -> bool {
+    token.chars().filter(char::is_ascii_digit).count() >= 7
+        && token
+            .chars()
+            .all(|c| c.is_ascii_digit() || " +-().".contains(c))
+}
+
+/// Fills the name, headline, location and contacts from the top lines.
+fn parse_top(lines: &[&str], draft: &mut AppState, doubts: &mut Vec<Doubt>) {
+    let mut texts = vec![];
+    for line in lines {
+        for token in line.split(['|', '•', '·', '\t']) {
+            let token = token.trim();
+            let bare = token.trim_start_matches("mailto:");
+            if token.is_empty() {
+                continue;
+            } else if bare.contains('@') && !bare.contains(' ') {
+                draft.basic.email = bare.to_string();
+            } else if is_phone(token) {
+                draft.basic.phone = token.to_string();
+            } else if let Some(kind) = link_kind(token) {
+                let url = with_scheme(token);
+                if !draft.basic.links.iter().any(|link| link.url == url) {
+                    draft.basic.links.push(ContactLink::new(kind, &url));
+                }
+            } else {
+                texts.push(token.to_string());
+            }
+        }
+    }
+    let mut texts = texts.into_iter();
+    if let Some(name) = texts.next() {
+        if name.split_whitespace().count() > 4 || name.chars().any(|c| c.is_ascii_digit()) {
+            doubts.push(Doubt {
+                section: None,
+                index: 0,
+                field: "Name".to_string(),
+                reason: "The first line doesn't look like a name",
+            });
+        }
+        draft.basic.name = name;
+    }
+    for text in texts {
+        if draft.basic.location.is_empty() && looks_like_location(&text) {
+            draft.basic.location = text;
+        } else if draft.basic.headline.is_empty() {
+            draft.basic.headline = text;
+        }
+    }
+}
+
+/// Builds a draft from pasted resume text. Sections the text doesn't have
+/// keep their current content.
+pub fn draft_from_text(state: &AppState, text: &str) -> TextImport {
+    let mut draft = state.clone();
+    let mut found: Vec<Section> = vec![];
+    let mut skipped = vec![];
+    let mut doubts = vec![];
+    for (block, lines) in split_blocks(text) {
+        let section = match block {
+            Block::Top => {
+                if lines.iter().any(|line| !line.trim().is_empty()) {
+                    draft.basic.email.clear();
+                    draft.basic.phone.clear();
+                    draft.basic.headline.clear();
+                    draft.basic.location.clear();
+                    draft.basic.links.clear();
+                    parse_top(&lines, &mut draft, &mut doubts);
+                }
+                continue;
+            }
+            Block::Skipped(name) => {
+                skipped.push(name);
+                continue;
+            }
+            Block::Section(section) => section,
+        };
+        // A repeated heading adds to the section rather than replacing it.
+        let first = !found.contains(&section);
+        if first {
+            found.push(section);
+        }
+        match section {
+            Section::Summary => {
+                let summary = parse_summary(&lines);
+                draft.summary = if first {
+                    summary
+                } else {
+                    format!("{}\n\n{}", draft.summary, summary)
+                };
+            }
+            Section::Experiences | Section::Volunteering => {
+                let target = if section == Section::Experiences {
+                    &mut draft.experiences
+                } else {
+                    &mut draft.volunteering
+                };
+                if first {
+                    target.clear();
+                }
+                // Doubt indexes count from the entries already parsed.
+                let offset = target.len();
+                let mut entry_doubts = vec![];
+                target.extend(parse_experiences(&lines, section, &mut entry_doubts));
+                doubts.extend(entry_doubts.into_iter().map(|doubt| Doubt {
+                    index: doubt.index + offset,
+                    ..doubt
+                }));
+            }
+            Section::Education => {
+                if first {
+                    draft.educations.clear();
+                }
+                let offset = draft.educations.len();
+                let mut entry_doubts = vec![];
+                draft
+                    .educations
+                    .extend(parse_educations(&lines, &mut entry_doubts));
+                doubts.extend(entry_doubts.into_iter().map(|doubt| Doubt {
+                    index: doubt.index + offset,
+                    ..doubt
+                }));
+            }
+            Section::Projects => {
+                if first {
+                    draft.projects.clear();
+                }
+                let offset = draft.projects.len();
+                let mut entry_doubts = vec![];
+                draft
+                    .projects
+                    .extend(parse_projects(&lines, &mut entry_doubts));
+                doubts.extend(entry_doubts.into_iter().map(|doubt| Doubt {
+                    index: doubt.index + offset,
+                    ..doubt
+                }));
+            }
+            Section::Certifications => {
+                if first {
+                    draft.certifications.clear();
+                }
+                let offset = draft.certifications.len();
+                let mut entry_doubts = vec![];
+                draft
+                    .certifications
+                    .extend(parse_certifications(&lines, &mut entry_doubts));
+                doubts.extend(entry_doubts.into_iter().map(|doubt| Doubt {
+                    index: doubt.index + offset,
+                    ..doubt
+                }));
+            }
+            Section::Skills => {
+                if first {
+                    draft.skills.clear();
+                }
+                draft.skills.extend(parse_skills(&lines));
+            }
+            _ => {}
+        }
+    }
+    TextImport {
+        draft,
+        found,
+        skipped,
+        doubts,
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct TextImportProps {
+    pub state: AppState,
+    pub on_import: Callback<AppState>,
+}
+
+/// Pastes a plain-text resume and reviews the draft before using it.
+#[function_component(TextImporter)]
+pub fn text_importer(props: &TextImportProps) -> Html {
+    let text = use_state(String::new);
+    let import = use_state(|| None::<TextImport>);
+
+    let update_text = {
+        let text = text.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlTextAreaElement = e.target_unchecked_into();
+            text.set(input.value());
+        })
+    };
+    let parse = {
+        let text = text.clone();
+        let import = import.clone();
+        let state = props.state.clone();
+        Callback::from(move |_| import.set(Some(draft_from_text(&state, &text))))
+    };
+
+    let review = import.as_ref().map(|found| {
+        let apply = {
+            let import = import.clone();
+            let text = text.clone();
+            let draft = found.draft.clone();
+            let on_import = props.on_import.clone();
+            Callback::from(move |_| {
+                on_import.emit(draft.clone());
+                import.set(None);
+                text.set(String::new());
+            })
+        };
+        let cancel = {
+            let import = import.clone();
+            Callback::from(move |_| import.set(None))
+        };
+        html! {
+            <>
+                <div class="max-h-80 overflow-y-auto py-1 text-xs text-slate-300">
+                    {review_draft(found)}
+                </div>
+                <div class="flex justify-end gap-x-2">
+                    <button class={SMALL_BUTTON_CLASS} onclick={cancel}> {"Cancel"} </button>
+                    <button class={SMALL_ADD_BUTTON_CLASS} onclick={apply}> {"Use Draft"} </button>
+                </div>
+            </>
+        }
+    });
+
+    html! {
+        <>
+            <div class={INPUT_SECTION_ROW_CLASS}>
+                <h5 class={INPUT_SECTION_CLASS}> {"Paste Resume"} </h5>
+            </div>
+            <div class={INPUT_DIVIDER_CLASS}></div>
+            <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                <div class="relative">
+                    <textarea
+                        class={TEXTAREA_CLASS}
+                        style="min-height: 96px"
+                        placeholder="Paste the text of an existing resume, with its section headings"
+                        oninput={update_text}
+                        value={(*text).clone()}
+                    />
+                    <label class={LABEL_CLASS}> {"Plain Text"} </label>
+                </div>
+                <div class="mt-1 flex justify-end">
+                    <button class={SMALL_ADD_BUTTON_CLASS} onclick={parse}> {"Build Draft"} </button>
+                </div>
+                {review.unwrap_or_default()}
+            </div>
+        </>
+    }
+}
+
+/// One field of the review, highlighted when the parser was unsure of it.
+fn review_field(
+    import: &TextImport,
+    section: Option<Section>,
+    index: usize,
+    field: &str,
+    value: String,
+) -> Html {
+    let value = if value.trim().is_empty() {
+        format!("(no {})", field.to_lowercase())
+    } else {
+        value
+    };
+    match import.doubt(section, index, field) {
+        Some(doubt) => html! {
+            <span class="rounded bg-amber-500/20 px-0.5 text-amber-300" title={doubt.reason}>
+                {value}
+            </span>
+        },
+        None => html! { <span> {value} </span> },
+    }
+}
+
+fn review_draft(import: &TextImport) -> Html {
+    let draft = &import.draft;
+    let today = current_month();
+    let dates = |range: &DateRange| range.format(&DateFormat::default(), today);
+    let heading = |section: Section| {
+        html! { <p class="mt-1 font-semibold text-slate-200"> {section.default_heading()} </p> }
+    };
+    let top = html! {
+        <p>
+            {review_field(import, None, 0, "Name", draft.basic.name.clone())}
+            {format!(" · {} · {}", draft.basic.headline, draft.basic.email)}
+        </p>
+    };
+    let sections = import
+        .found
+        .iter()
+        .map(|section| {
+            let section = *section;
+            let at = Some(section);
+            let entries = match section {
+                Section::Experiences | Section::Volunteering => {
+                    let experiences = if section == Section::Experiences {
+                        &draft.experiences
+                    } else {
+                        &draft.volunteering
+                    };
+                    experiences
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, experience)| {
+                            let roles = experience
+                                .roles
+                                .iter()
+                                .enumerate()
+                                .map(|(role_idx, role)| {
+                                    html! {
+                                        <li>
+                                            {review_field(import, at, idx, &role_field("Title", role_idx), role.title.clone())}
+                                            {" · "}
+                                            {review_field(import, at, idx, &role_field("Dates", role_idx), dates(&role.dates))}
+                                            {format!(" · {} bullets", role.bullets.len())}
+                                        </li>
+                                    }
+                                })
+                                .collect::<Html>();
+                            html! {
+                                <li>
+                                    {review_field(import, at, idx, "Employer", experience.employer.clone())}
+                                    <ul class="pl-3"> {roles} </ul>
+                                </li>
+                            }
+                        })
+                        .collect::<Html>()
+                }
+                Section::Education => draft
+                    .educations
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, education)| {
+                        html! {
+                            <li>
+                                {review_field(import, at, idx, "School", education.school.clone())}
+                                {" · "}
+                                {review_field(import, at, idx, "Degree", education.degree.clone())}
+                                {" · "}
+                                {review_field(import, at, idx, "Dates", dates(&education.dates))}
+                            </li>
+                        }
+                    })
+                    .collect::<Html>(),
+                Section::Projects => draft
+                    .projects
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, project)| {
+                        html! {
+                            <li>
+                                {review_field(import, at, idx, "Name", project.name.clone())}
+                                {format!(" · {} bullets", project.bullets.len())}
+                            </li>
+                        }
+                    })
+                    .collect::<Html>(),
+                Section::Certifications => draft
+                    .certifications
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, certification)| {
+                        let issued = certification
+                            .issued
+                            .map(|date| date.format(DateFormat::default().style))
+                            .unwrap_or_default();
+                        html! {
+                            <li>
+                                {format!("{} · {} · ", certification.name, certification.issuer)}
+                                {review_field(import, at, idx, "Issued", issued)}
+                            </li>
+                        }
+                    })
+                    .collect::<Html>(),
+                Section::Skills => draft
+                    .skills
+                    .iter()
+                    .map(|category| {
+                        html! {
+                            <li> {format!("{}: {} skills", category.category, category.skills.len())} </li>
+                        }
+                    })
+                    .collect::<Html>(),
+                Section::Summary => html! {
+                    <li> {format!("{} characters", draft.summary.chars().count())} </li>
+                },
+                _ => html! {},
+            };
+            html! {
+                <>
+                    {heading(section)}
+                    <ul class="list-disc pl-4"> {entries} </ul>
+                </>
+            }
+        })
+        .collect::<Html>();
+    let skipped = if import.skipped.is_empty() {
+        html! {}
+    } else {
+        html! {
+            <p class="mt-1 text-slate-400">
+                {format!("Not imported: {}", import.skipped.join(", "))}
+            </p>
+        }
+    };
+    html! {
+        <>
+            {top}
+            {sections}
+            {skipped}
+            <p class="mt-1 text-slate-400">
+                {format!(
+                    "{} highlighted fields need a look. Sections not in the text are left as they are.",
+                    import.doubts.len()
+                )}
+            </p>
+        </>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::dates::{DateEnd, PartialDate};
+    use crate::pages::state::default_seed_state;
+
+    const RESUME: &str = "\
+Jane Doe
+Backend Engineer | Austin, TX
+jane@example.com | (512) 555-0100 | linkedin.com/in/janedoe
+
+SUMMARY
+Backend engineer who likes
+boring, reliable systems.
+
+Work Experience
+Acme Corp | Senior Software Engineer | Jan 2021 – Present
+• Led the move to event sourcing,
+  cutting incident count by half
+• Mentored four engineers
+Software Engineer, Jun 2019 - Dec 2020
+- Built the billing service
+
+Initech
+2016 - 2019
+* Wrote TPS reports
+
+EDUCATION
+University of Texas at Austin — B.S. in Computer Science, 2012–2016
+
+Skills
+Languages: Rust, Go, Python
+Docker, Kubernetes
+
+Interests
+Climbing
+";
+
+    #[test]
+    fn splits_sections_and_entries() {
+        let import = draft_from_text(&default_seed_state(), RESUME);
+        let draft = &import.draft;
+        assert_eq!(draft.basic.name, "Jane Doe");
+        assert_eq!(draft.basic.headline, "Backend Engineer");
+        assert_eq!(draft.basic.location, "Austin, TX");
+        assert_eq!(draft.basic.email, "jane@example.com");
+        assert_eq!(draft.basic.phone, "(512) 555-0100");
+        assert_eq!(draft.basic.links[0].kind, LinkKind::LinkedIn);
+        assert_eq!(
+            draft.summary,
+            "Backend engineer who likes boring, reliable systems."
+        );
+
+        assert_eq!(draft.experiences.len(), 2);
+        let acme = &draft.experiences[0];
+        assert_eq!(acme.employer, "Acme Corp");
+        assert_eq!(acme.roles.len(), 2);
+        assert_eq!(acme.roles[0].title, "Senior Software Engineer");
+        assert_eq!(acme.roles[0].dates.end, Some(DateEnd::Present));
+        assert_eq!(
+            acme.roles[0].bullets[0].text,
+            "Led the move to event sourcing, cutting incident count by half"
+        );
+        assert_eq!(acme.roles[1].title, "Software Engineer");
+        assert_eq!(
+            acme.roles[1].dates.start,
+            Some(PartialDate {
+                year: 2019,
+                month: 6,
+                day: None
+            })
+        );
+
+        assert_eq!(draft.educations.len(), 1);
+        assert_eq!(draft.educations[0].school, "University of Texas at Austin");
+        assert_eq!(draft.educations[0].degree, "B.S.");
+        assert_eq!(draft.educations[0].major, "Computer Science");
+
+        assert_eq!(draft.skills.len(), 2);
+        assert_eq!(draft.skills[0].category, "Languages");
+        assert_eq!(draft.skills[1].skills.len(), 2);
+        assert_eq!(import.skipped, vec!["Interests".to_string()]);
+        // Untouched sections keep their content.
+        assert_eq!(draft.projects, default_seed_state().projects);
+    }
+
+    #[test]
+    fn flags_low_confidence_fields() {
+        let import = draft_from_text(&default_seed_state(), RESUME);
+        let at = Some(Section::Experiences);
+        assert!(import.doubt(at, 0, "Employer").is_none());
+        assert!(import.doubt(at, 1, "Title").is_some());
+        assert!(import.doubt(at, 1, "Dates").is_some());
+        assert!(import.doubt(Some(Section::Education), 0, "Dates").is_some());
+    }
+}
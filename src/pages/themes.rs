@@ -0,0 +1,200 @@
+use serde::{Deserialize, Serialize};
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+use super::html_utils::{
+    INPUT_CLASS, INPUT_DIVIDER_CLASS, INPUT_FIELD_WRAPPER_CLASS, INPUT_SECTION_CLASS,
+    INPUT_SECTION_ROW_CLASS, LABEL_CLASS,
+};
+use super::sections::Section;
+
+/// The look of the rendered resume.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum Theme {
+    #[default]
+    Classic,
+    Modern,
+    Compact,
+    /// Skills, languages and certifications in a narrow left column.
+    TwoColumn,
+    Academic,
+}
+
+/// Tailwind classes the viewers render with.
+pub struct ThemeStyles {
+    /// The resume page: font, text size and margins.
+    pub page: &'static str,
+    pub name: &'static str,
+    pub headline: &'static str,
+    pub section_header: &'static str,
+    /// An entry's first line: what and where on the left, when on the right.
+    pub entry_row: &'static str,
+    pub entry_meta: &'static str,
+    /// Space after each experience.
+    pub entry_gap: &'static str,
+    /// Puts skill categories above their skills instead of beside them.
+    pub stack_labels: bool,
+}
+
+const CLASSIC: ThemeStyles = ThemeStyles {
+    page: "font-['Arial'] text-lg tracking-normal p-10",
+    name: "text-4xl",
+    headline: "text-2xl",
+    section_header: "text-2xl font-bold text-left w-full border-b-2 border-black mt-2",
+    entry_row: "grid grid-cols-[minmax(0,1fr)_auto] items-start gap-x-1 mt-0",
+    entry_meta: "flex gap-x-1 whitespace-nowrap",
+    entry_gap: "mb-3",
+    stack_labels: false,
+};
+
+const MODERN: ThemeStyles = ThemeStyles {
+    page: "font-['Helvetica'] text-lg tracking-normal p-10",
+    name: "text-4xl font-light tracking-tight",
+    headline: "text-2xl text-sky-800",
+    section_header: "text-xl font-semibold uppercase tracking-wide text-left text-sky-800 w-full border-b border-sky-800/40 mt-4 mb-1",
+    entry_row: "grid grid-cols-[minmax(0,1fr)_auto] items-start gap-x-2 mt-0",
+    entry_meta: "flex gap-x-1 whitespace-nowrap text-slate-600",
+    entry_gap: "mb-3",
+    stack_labels: false,
+};
+
+const COMPACT: ThemeStyles = ThemeStyles {
+    page: "font-['Arial'] text-base leading-snug tracking-tight p-6",
+    name: "text-3xl",
+    headline: "text-xl",
+    section_header: "text-lg font-bold uppercase text-left w-full border-b border-black mt-1",
+    entry_row: "grid grid-cols-[minmax(0,1fr)_auto] items-start gap-x-1 mt-0",
+    entry_meta: "flex gap-x-1 whitespace-nowrap",
+    entry_gap: "mb-1",
+    stack_labels: false,
+};
+
+const TWO_COLUMN: ThemeStyles = ThemeStyles {
+    page: "font-['Arial'] text-base tracking-normal p-8",
+    name: "text-4xl font-semibold",
+    headline: "text-xl text-gray-700",
+    section_header:
+        "text-lg font-bold uppercase tracking-wide text-left w-full border-b-2 border-black mt-3",
+    entry_row: "grid grid-cols-[minmax(0,1fr)_auto] items-start gap-x-1 mt-0",
+    entry_meta: "flex gap-x-1 whitespace-nowrap text-gray-700",
+    entry_gap: "mb-2",
+    // Skills sit in the narrow column.
+    stack_labels: true,
+};
+
+const ACADEMIC: ThemeStyles = ThemeStyles {
+    page: "font-['Georgia'] text-lg tracking-normal p-12",
+    name: "text-4xl font-semibold",
+    headline: "text-xl italic",
+    section_header: "text-xl font-semibold uppercase tracking-widest text-left w-full border-b border-black mt-4 mb-1",
+    entry_row: "grid grid-cols-[minmax(0,1fr)_auto] items-start gap-x-1 mt-0",
+    entry_meta: "flex gap-x-1 whitespace-nowrap italic",
+    entry_gap: "mb-3",
+    stack_labels: false,
+};
+
+impl Theme {
+    pub fn all() -> [Theme; 5] {
+        [
+            Theme::Classic,
+            Theme::Modern,
+            Theme::Compact,
+            Theme::TwoColumn,
+            Theme::Academic,
+        ]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Theme::Classic => "Classic",
+            Theme::Modern => "Modern",
+            Theme::Compact => "Compact",
+            Theme::TwoColumn => "Two-Column",
+            Theme::Academic => "Academic",
+        }
+    }
+
+    pub fn styles(&self) -> &'static ThemeStyles {
+        match self {
+            Theme::Classic => &CLASSIC,
+            Theme::Modern => &MODERN,
+            Theme::Compact => &COMPACT,
+            Theme::TwoColumn => &TWO_COLUMN,
+            Theme::Academic => &ACADEMIC,
+        }
+    }
+
+    /// Whether `section` goes in the narrow side column.
+    pub fn in_side_column(&self, section: Section) -> bool {
+        *self == Theme::TwoColumn
+            && matches!(
+                section,
+                Section::Skills | Section::Languages | Section::Certifications
+            )
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ThemeControllerProps {
+    pub value: Theme,
+    pub on_change: Callback<Theme>,
+}
+
+#[function_component(ThemeController)]
+pub fn theme_controller(props: &ThemeControllerProps) -> Html {
+    let update_theme = {
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Some(theme) = Theme::all()
+                .into_iter()
+                .find(|theme| format!("{:?}", theme) == select.value())
+            {
+                on_change.emit(theme);
+            }
+        })
+    };
+    let options = Theme::all()
+        .into_iter()
+        .map(|theme| {
+            html! {
+                <option value={format!("{:?}", theme)} selected={props.value == theme}>
+                    {theme.label()}
+                </option>
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <>
+            <div class={INPUT_SECTION_ROW_CLASS}>
+                <h5 class={INPUT_SECTION_CLASS}> {"Template"} </h5>
+            </div>
+            <div class={INPUT_DIVIDER_CLASS}></div>
+            <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                <div class="relative">
+                    <select class={INPUT_CLASS} onchange={update_theme}>
+                        {options}
+                    </select>
+                    <label class={LABEL_CLASS}> {"Theme"} </label>
+                </div>
+            </div>
+        </>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_two_column_uses_a_side_column() {
+        for theme in Theme::all() {
+            assert_eq!(
+                theme.in_side_column(Section::Skills),
+                theme == Theme::TwoColumn
+            );
+            assert!(!theme.in_side_column(Section::Experiences));
+        }
+    }
+}
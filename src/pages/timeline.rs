@@ -0,0 +1,439 @@
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsCast;
+use web_sys::{window, HtmlInputElement};
+use yew::prelude::*;
+
+use super::dates::{current_month, format_duration, DateEnd, DateRange, PartialDate};
+use super::education::Education;
+use super::experiences::Experience;
+use super::html_utils::{
+    INPUT_CLASS, INPUT_DIVIDER_CLASS, INPUT_FIELD_WRAPPER_CLASS, INPUT_SECTION_CLASS,
+    INPUT_SECTION_ROW_CLASS, LABEL_CLASS, SUBSECTION_LABEL_CLASS,
+};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct TimelineSettings {
+    /// Gaps of at most this many months are not reported.
+    #[serde(default = "default_gap_threshold")]
+    pub gap_threshold_months: u32,
+}
+
+fn default_gap_threshold() -> u32 {
+    3
+}
+
+impl Default for TimelineSettings {
+    fn default() -> Self {
+        TimelineSettings {
+            gap_threshold_months: default_gap_threshold(),
+        }
+    }
+}
+
+/// An editor entry the analyzer can point back to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EntryRef {
+    Role { experience: usize, role: usize },
+    Education(usize),
+}
+
+impl EntryRef {
+    /// The id of the matching element in the sidebar editor.
+    pub fn anchor_id(&self) -> String {
+        match self {
+            EntryRef::Role { experience, role } => {
+                format!("experience-{}-role-{}", experience, role)
+            }
+            EntryRef::Education(idx) => format!("education-{}", idx),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Gap {
+    pub after: EntryRef,
+    pub before: EntryRef,
+    pub months: i32,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Overlap {
+    pub first: EntryRef,
+    pub second: EntryRef,
+    pub months: i32,
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct TimelineReport {
+    pub gaps: Vec<Gap>,
+    pub overlaps: Vec<Overlap>,
+}
+
+struct Span {
+    entry: EntryRef,
+    /// Owning experience, so roles at the same employer are not flagged
+    /// as overlapping each other.
+    group: Option<usize>,
+    start: i32,
+    end: i32,
+}
+
+/// Months covered by a range as `[start, end)`. An end date is a month that
+/// was worked or studied, so it is covered too, as is the current month for
+/// ranges ending "Present". Ranges without a start cannot
+/// be placed and are skipped; a missing end counts as a single month.
+pub fn span_months(range: &DateRange, today: PartialDate) -> Option<(i32, i32)> {
+    let start = range.start?.months_since_epoch();
+    let end = match range.end {
+        Some(DateEnd::Present) => today.months_since_epoch() + 1,
+        Some(DateEnd::On(date)) => date.months_since_epoch() + 1,
+        None => start + 1,
+    };
+    Some((start, end.max(start + 1)))
+}
+
+fn collect_spans(
+    experiences: &[Experience],
+    educations: &[Education],
+    today: PartialDate,
+) -> Vec<Span> {
+    let roles = experiences
+        .iter()
+        .enumerate()
+        .flat_map(|(experience, exp)| {
+            exp.roles.iter().enumerate().filter_map(move |(role, r)| {
+                let (start, end) = span_months(&r.dates, today)?;
+                Some(Span {
+                    entry: EntryRef::Role { experience, role },
+                    group: Some(experience),
+                    start,
+                    end,
+                })
+            })
+        });
+    let schools = educations
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, education)| {
+            let (start, end) = span_months(&education.dates, today)?;
+            Some(Span {
+                entry: EntryRef::Education(idx),
+                group: None,
+                start,
+                end,
+            })
+        });
+    let mut spans = roles.chain(schools).collect::<Vec<_>>();
+    spans.sort_by_key(|span| (span.start, span.end));
+    spans
+}
+
+/// Finds gaps longer than the threshold between dated roles and schools,
+/// and roles or schools that overlap one another.
+pub fn analyze_timeline(
+    experiences: &[Experience],
+    educations: &[Education],
+    settings: &TimelineSettings,
+    today: PartialDate,
+) -> TimelineReport {
+    let spans = collect_spans(experiences, educations, today);
+    let mut report = TimelineReport::default();
+
+    let mut covered: Option<&Span> = None;
+    for span in &spans {
+        if let Some(last) = covered {
+            let months = span.start - last.end;
+            if months > settings.gap_threshold_months as i32 {
+                report.gaps.push(Gap {
+                    after: last.entry,
+                    before: span.entry,
+                    months,
+                });
+            }
+        }
+        if covered.is_none_or(|last| span.end > last.end) {
+            covered = Some(span);
+        }
+    }
+
+    for (idx, first) in spans.iter().enumerate() {
+        for second in spans[idx + 1..]
+            .iter()
+            .take_while(|second| second.start < first.end)
+        {
+            if first.group.is_some() && first.group == second.group {
+                continue;
+            }
+            report.overlaps.push(Overlap {
+                first: first.entry,
+                second: second.entry,
+                months: first.end.min(second.end) - second.start,
+            });
+        }
+    }
+    report
+}
+
+fn entry_label(entry: EntryRef, experiences: &[Experience], educations: &[Education]) -> String {
+    match entry {
+        EntryRef::Role { experience, role } => experiences
+            .get(experience)
+            .map(|exp| {
+                let title = exp.roles.get(role).map(|r| r.title.as_str()).unwrap_or("");
+                format!("{} @ {}", title, exp.employer)
+            })
+            .unwrap_or_default(),
+        EntryRef::Education(idx) => educations
+            .get(idx)
+            .map(|education| format!("{} @ {}", education.degree, education.school))
+            .unwrap_or_default(),
+    }
+}
+
+fn scroll_to_entry(entry: EntryRef) {
+    let element = window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.get_element_by_id(&entry.anchor_id()));
+    if let Some(element) = element {
+        element.scroll_into_view();
+        if let Ok(element) = element.dyn_into::<web_sys::HtmlElement>() {
+            let _ = element.focus();
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct TimelinePanelProps {
+    pub experiences: Vec<Experience>,
+    pub educations: Vec<Education>,
+    pub settings: TimelineSettings,
+    pub on_settings_change: Callback<TimelineSettings>,
+}
+
+#[function_component(TimelinePanel)]
+pub fn timeline_panel(props: &TimelinePanelProps) -> Html {
+    let report = analyze_timeline(
+        &props.experiences,
+        &props.educations,
+        &props.settings,
+        current_month(),
+    );
+
+    let update_threshold = {
+        let on_change = props.on_settings_change.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Ok(gap_threshold_months) = input.value().parse() {
+                on_change.emit(TimelineSettings {
+                    gap_threshold_months,
+                });
+            }
+        })
+    };
+
+    let link = |entry: EntryRef| {
+        let onclick = Callback::from(move |_| scroll_to_entry(entry));
+        html! {
+            <button class="underline text-sky-300 hover:text-sky-200" {onclick}>
+                {entry_label(entry, &props.experiences, &props.educations)}
+            </button>
+        }
+    };
+
+    let gaps = report
+        .gaps
+        .iter()
+        .map(|gap| {
+            html! {
+                <li class="px-1 py-0.5 text-xs text-slate-300">
+                    {format!("{} gap between ", format_duration(gap.months))}
+                    {link(gap.after)}
+                    {" and "}
+                    {link(gap.before)}
+                </li>
+            }
+        })
+        .collect::<Html>();
+    let overlaps = report
+        .overlaps
+        .iter()
+        .map(|overlap| {
+            html! {
+                <li class="px-1 py-0.5 text-xs text-slate-300">
+                    {link(overlap.first)}
+                    {" overlaps "}
+                    {link(overlap.second)}
+                    {format!(" for {}", format_duration(overlap.months))}
+                </li>
+            }
+        })
+        .collect::<Html>();
+
+    let findings = if report.gaps.is_empty() && report.overlaps.is_empty() {
+        html! {
+            <p class="px-1 py-0.5 text-xs text-slate-400"> {"No gaps or overlaps found."} </p>
+        }
+    } else {
+        html! {
+            <>
+                if !report.gaps.is_empty() {
+                    <div class={SUBSECTION_LABEL_CLASS}> {"Gaps"} </div>
+                    <ul> {gaps} </ul>
+                }
+                if !report.overlaps.is_empty() {
+                    <div class={SUBSECTION_LABEL_CLASS}> {"Overlaps"} </div>
+                    <ul> {overlaps} </ul>
+                }
+            </>
+        }
+    };
+
+    html! {
+        <>
+            <div class={INPUT_SECTION_ROW_CLASS}>
+                <h5 class={INPUT_SECTION_CLASS}> {"Timeline Check"} </h5>
+            </div>
+            <div class={INPUT_DIVIDER_CLASS}></div>
+            <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                <div class="relative">
+                    <input type="number"
+                           min="0"
+                           class={INPUT_CLASS}
+                           value={props.settings.gap_threshold_months.to_string()}
+                           oninput={update_threshold}
+                    />
+                    <label class={LABEL_CLASS}> {"Report gaps longer than (months)"} </label>
+                </div>
+                {findings}
+            </div>
+        </>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::experiences::RolePeriod;
+
+    fn role(dates: &str) -> RolePeriod {
+        RolePeriod {
+            dates: DateRange::parse(dates),
+            ..Default::default()
+        }
+    }
+
+    fn today() -> PartialDate {
+        PartialDate {
+            year: 2026,
+            month: 10,
+            day: None,
+        }
+    }
+
+    #[test]
+    fn reports_gaps_over_threshold() {
+        let experiences = vec![
+            Experience {
+                roles: vec![role("2019/01 - 2019/06")],
+                ..Default::default()
+            },
+            Experience {
+                roles: vec![role("2019/08 - 2020/01"), role("2020/09 - Present")],
+                ..Default::default()
+            },
+        ];
+
+        let report = analyze_timeline(&experiences, &[], &TimelineSettings::default(), today());
+        assert_eq!(
+            report.gaps,
+            [Gap {
+                after: EntryRef::Role {
+                    experience: 1,
+                    role: 0
+                },
+                before: EntryRef::Role {
+                    experience: 1,
+                    role: 1
+                },
+                months: 7,
+            }]
+        );
+        assert!(report.overlaps.is_empty());
+
+        // Only July 2019 is missing between the first two roles.
+        let settings = TimelineSettings {
+            gap_threshold_months: 0,
+        };
+        let report = analyze_timeline(&experiences, &[], &settings, today());
+        assert_eq!(report.gaps[0].months, 1);
+    }
+
+    #[test]
+    fn reports_overlaps_across_entries_only() {
+        let experiences = vec![Experience {
+            roles: vec![role("2020/05 - 2020/09"), role("2020/08 - 2020/12")],
+            ..Default::default()
+        }];
+        let educations = vec![Education {
+            dates: DateRange::from_parts("2017/08", "2021/05"),
+            ..Default::default()
+        }];
+
+        let report = analyze_timeline(
+            &experiences,
+            &educations,
+            &TimelineSettings::default(),
+            today(),
+        );
+        let overlapping = report
+            .overlaps
+            .iter()
+            .map(|overlap| (overlap.first, overlap.second, overlap.months))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            overlapping,
+            [
+                (
+                    EntryRef::Education(0),
+                    EntryRef::Role {
+                        experience: 0,
+                        role: 0
+                    },
+                    5
+                ),
+                (
+                    EntryRef::Education(0),
+                    EntryRef::Role {
+                        experience: 0,
+                        role: 1
+                    },
+                    5
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn ongoing_roles_cover_the_current_month() {
+        let experiences = vec![
+            Experience {
+                roles: vec![role("2025/01 - Present")],
+                ..Default::default()
+            },
+            Experience {
+                roles: vec![role("2026/10 - Present")],
+                ..Default::default()
+            },
+        ];
+
+        let report = analyze_timeline(&experiences, &[], &TimelineSettings::default(), today());
+        assert_eq!(
+            report
+                .overlaps
+                .iter()
+                .map(|overlap| overlap.months)
+                .collect::<Vec<_>>(),
+            [1]
+        );
+    }
+}
@@ -0,0 +1,485 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use super::html_utils::{
+    ADD_BUTTON_CLASS, INPUT_CLASS, INPUT_DIVIDER_CLASS, INPUT_FIELD_WRAPPER_CLASS,
+    INPUT_SECTION_CLASS, INPUT_SECTION_ROW_CLASS, LABEL_CLASS, REMOVE_BUTTON_CLASS,
+    SMALL_BUTTON_CLASS, SUBSECTION_LABEL_CLASS,
+};
+use super::sections::{normalize_section_order, Section};
+use super::state::AppState;
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct Variant {
+    pub name: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub section_order: Vec<Section>,
+    #[serde(default)]
+    pub headings: BTreeMap<Section, String>,
+}
+
+/// Untagged content belongs to every variant, and a variant without tags
+/// selects everything.
+pub fn tags_match(item_tags: &[String], selected: &[String]) -> bool {
+    if item_tags.is_empty() || selected.is_empty() {
+        return true;
+    }
+    item_tags
+        .iter()
+        .any(|tag| selected.iter().any(|s| s.eq_ignore_ascii_case(tag)))
+}
+
+pub fn parse_tags(raw: &str) -> Vec<String> {
+    raw.split(',')
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
+/// Splits a trailing `{tag, tag}` block off a legacy description line.
+pub fn split_line_tags(line: &str) -> (&str, Vec<String>) {
+    let trimmed = line.trim_end();
+    if !trimmed.ends_with('}') {
+        return (line, vec![]);
+    }
+    match trimmed.rfind('{') {
+        Some(start) => (
+            trimmed[..start].trim_end(),
+            parse_tags(&trimmed[start + 1..trimmed.len() - 1]),
+        ),
+        None => (line, vec![]),
+    }
+}
+
+/// Returns a copy of `state` containing only the content selected by `variant`.
+pub fn apply_variant(state: &AppState, variant: Option<&Variant>) -> AppState {
+    let Some(variant) = variant else {
+        return state.clone();
+    };
+    let selected = &variant.tags;
+    let mut filtered = state.clone();
+    filtered.skills.retain(|s| tags_match(&s.tags, selected));
+    filtered
+        .educations
+        .retain(|e| tags_match(&e.tags, selected));
+    filtered.projects.retain(|p| tags_match(&p.tags, selected));
+    filtered
+        .open_sources
+        .retain(|o| tags_match(&o.tags, selected));
+    filtered
+        .certifications
+        .retain(|c| tags_match(&c.tags, selected));
+    filtered.languages.retain(|l| tags_match(&l.tags, selected));
+    filtered
+        .references
+        .retain(|r| tags_match(&r.tags, selected));
+    filtered
+        .publications
+        .retain(|p| tags_match(&p.tags, selected));
+    filtered
+        .experiences
+        .retain(|e| tags_match(&e.tags, selected));
+    filtered
+        .volunteering
+        .retain(|v| tags_match(&v.tags, selected));
+    for experience in filtered
+        .experiences
+        .iter_mut()
+        .chain(filtered.volunteering.iter_mut())
+    {
+        experience
+            .bullets
+            .retain(|bullet| tags_match(&bullet.tags, selected));
+        for role in experience.roles.iter_mut() {
+            role.bullets
+                .retain(|bullet| tags_match(&bullet.tags, selected));
+        }
+    }
+    for project in filtered.projects.iter_mut() {
+        project
+            .bullets
+            .retain(|bullet| tags_match(&bullet.tags, selected));
+    }
+    for education in filtered.educations.iter_mut() {
+        education
+            .bullets
+            .retain(|bullet| tags_match(&bullet.tags, selected));
+    }
+    filtered
+}
+
+/// The sections to render, in order, paired with their headings.
+pub fn section_layout(state: &AppState, variant: Option<&Variant>) -> Vec<(Section, String)> {
+    let order = match variant {
+        Some(variant) if !variant.section_order.is_empty() => &variant.section_order,
+        _ => &state.section_order,
+    };
+    normalize_section_order(order)
+        .into_iter()
+        .map(|section| {
+            let heading = variant
+                .and_then(|v| v.headings.get(&section))
+                .filter(|h| !h.trim().is_empty())
+                .cloned()
+                .unwrap_or_else(|| section.default_heading().to_string());
+            (section, heading)
+        })
+        .collect()
+}
+
+#[derive(Properties, PartialEq)]
+pub struct TagsInputProps {
+    pub value: Vec<String>,
+    pub on_change: Callback<Vec<String>>,
+    #[prop_or(AttrValue::from("Tags"))]
+    pub label: AttrValue,
+}
+
+#[function_component(TagsInput)]
+pub fn tags_input(props: &TagsInputProps) -> Html {
+    // Parsed on change rather than on input so that typing a comma isn't
+    // immediately normalized away.
+    let onchange = {
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            on_change.emit(parse_tags(&input.value()));
+        })
+    };
+    html! {
+    <div class={INPUT_FIELD_WRAPPER_CLASS}>
+        <div class="relative">
+            <input type="text"
+                   onchange={onchange}
+                   class={INPUT_CLASS}
+                   value={props.value.join(", ")}
+            />
+            <label class={LABEL_CLASS}>
+                {props.label.clone()}
+            </label>
+        </div>
+    </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct VariantControllerProps {
+    pub value: Vec<Variant>,
+    pub section_order: Vec<Section>,
+    pub active: Option<usize>,
+    pub on_change: Callback<Vec<Variant>>,
+    pub on_section_order_change: Callback<Vec<Section>>,
+    pub on_select: Callback<Option<usize>>,
+}
+
+#[function_component(VariantController)]
+pub fn variant_controller(props: &VariantControllerProps) -> Html {
+    let active = props
+        .active
+        .and_then(|idx| props.value.get(idx).map(|v| (idx, v)));
+
+    let add_variant = {
+        let variants = props.value.clone();
+        let on_change = props.on_change.clone();
+        let on_select = props.on_select.clone();
+        Callback::from(move |_| {
+            let mut next = variants.clone();
+            next.push(Variant {
+                name: format!("Variant {}", next.len() + 1),
+                ..Variant::default()
+            });
+            let idx = next.len() - 1;
+            on_change.emit(next);
+            on_select.emit(Some(idx));
+        })
+    };
+
+    let select_variant = {
+        let on_select = props.on_select.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            on_select.emit(select.value().parse::<usize>().ok());
+        })
+    };
+
+    let options = props
+        .value
+        .iter()
+        .enumerate()
+        .map(|(idx, variant)| {
+            html! {
+                <option value={idx.to_string()} selected={props.active == Some(idx)}>
+                    {variant.name.clone()}
+                </option>
+            }
+        })
+        .collect::<Html>();
+
+    let variant_inputs = match active {
+        Some((idx, variant)) => {
+            let update_name = {
+                let variants = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |e: InputEvent| {
+                    let input: HtmlInputElement = e.target_unchecked_into();
+                    let mut next = variants.clone();
+                    if let Some(variant) = next.get_mut(idx) {
+                        variant.name = input.value();
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let update_tags = {
+                let variants = props.value.clone();
+                let on_change = props.on_change.clone();
+                Callback::from(move |tags: Vec<String>| {
+                    let mut next = variants.clone();
+                    if let Some(variant) = next.get_mut(idx) {
+                        variant.tags = tags;
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let remove_variant = {
+                let variants = props.value.clone();
+                let on_change = props.on_change.clone();
+                let on_select = props.on_select.clone();
+                Callback::from(move |_| {
+                    let mut next = variants.clone();
+                    if idx < next.len() {
+                        next.remove(idx);
+                        on_select.emit(None);
+                        on_change.emit(next);
+                    }
+                })
+            };
+            let heading_inputs = Section::all()
+                .into_iter()
+                .map(|section| {
+                    let update_heading = {
+                        let variants = props.value.clone();
+                        let on_change = props.on_change.clone();
+                        Callback::from(move |e: InputEvent| {
+                            let input: HtmlInputElement = e.target_unchecked_into();
+                            let mut next = variants.clone();
+                            if let Some(variant) = next.get_mut(idx) {
+                                if input.value().is_empty() {
+                                    variant.headings.remove(&section);
+                                } else {
+                                    variant.headings.insert(section, input.value());
+                                }
+                                on_change.emit(next);
+                            }
+                        })
+                    };
+                    html! {
+                        <div class="relative">
+                            <input type="text"
+                                   class={INPUT_CLASS}
+                                   placeholder={section.default_heading()}
+                                   value={variant.headings.get(&section).cloned().unwrap_or_default()}
+                                   oninput={update_heading}
+                            />
+                            <label class={LABEL_CLASS}> {format!("{} Heading", section.default_heading())} </label>
+                        </div>
+                    }
+                })
+                .collect::<Html>();
+            html! {
+                <>
+                    <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                        <div class="relative">
+                            <input type="text"
+                                   class={INPUT_CLASS}
+                                   value={variant.name.clone()}
+                                   oninput={update_name}
+                            />
+                            <label class={LABEL_CLASS}> {"Variant Name"} </label>
+                        </div>
+                    </div>
+                    <TagsInput value={variant.tags.clone()} on_change={update_tags} />
+                    <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                        <div class="grid grid-cols-1 md:grid-cols-2 gap-1">
+                            {heading_inputs}
+                        </div>
+                    </div>
+                    <button class={REMOVE_BUTTON_CLASS} onclick={remove_variant}>
+                        {"Remove Variant"}
+                    </button>
+                </>
+            }
+        }
+        None => html! {},
+    };
+
+    let order = match active {
+        Some((_, variant)) if !variant.section_order.is_empty() => {
+            normalize_section_order(&variant.section_order)
+        }
+        _ => normalize_section_order(&props.section_order),
+    };
+    let emit_order = {
+        let variants = props.value.clone();
+        let on_change = props.on_change.clone();
+        let on_section_order_change = props.on_section_order_change.clone();
+        let active_idx = active.map(|(idx, _)| idx);
+        move |order: Vec<Section>| match active_idx {
+            Some(idx) => {
+                let mut next = variants.clone();
+                if let Some(variant) = next.get_mut(idx) {
+                    variant.section_order = order;
+                    on_change.emit(next);
+                }
+            }
+            None => on_section_order_change.emit(order),
+        }
+    };
+    let order_rows = order
+        .iter()
+        .enumerate()
+        .map(|(pos, section)| {
+            let move_up = {
+                let order = order.clone();
+                let emit_order = emit_order.clone();
+                Callback::from(move |_| {
+                    if pos > 0 {
+                        let mut next = order.clone();
+                        next.swap(pos, pos - 1);
+                        emit_order(next);
+                    }
+                })
+            };
+            let move_down = {
+                let order = order.clone();
+                let emit_order = emit_order.clone();
+                Callback::from(move |_| {
+                    if pos + 1 < order.len() {
+                        let mut next = order.clone();
+                        next.swap(pos, pos + 1);
+                        emit_order(next);
+                    }
+                })
+            };
+            html! {
+                <div class="flex items-center justify-between px-1 py-0.5 text-sm text-slate-300">
+                    <span> {section.default_heading()} </span>
+                    <div class="flex gap-x-1">
+                        <button class={SMALL_BUTTON_CLASS} onclick={move_up}> {"Up"} </button>
+                        <button class={SMALL_BUTTON_CLASS} onclick={move_down}> {"Down"} </button>
+                    </div>
+                </div>
+            }
+        })
+        .collect::<Html>();
+    let reset_order = match active {
+        Some((_, variant)) if !variant.section_order.is_empty() => {
+            let emit_order = emit_order.clone();
+            html! {
+                <button class={SMALL_BUTTON_CLASS} onclick={Callback::from(move |_| emit_order(vec![]))}>
+                    {"Use Master Order"}
+                </button>
+            }
+        }
+        _ => html! {},
+    };
+
+    html! {
+        <>
+            <div class={INPUT_SECTION_ROW_CLASS}>
+                <h5 class={INPUT_SECTION_CLASS}> {"Variants"} </h5>
+                <button
+                    class={ADD_BUTTON_CLASS}
+                    onclick={add_variant}
+                >
+                    {"Add New"}
+                </button>
+            </div>
+            <div class={INPUT_DIVIDER_CLASS}></div>
+            <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                <div class="relative">
+                    <select class={INPUT_CLASS} onchange={select_variant}>
+                        <option value="" selected={active.is_none()}> {"Master (all content)"} </option>
+                        {options}
+                    </select>
+                    <label class={LABEL_CLASS}> {"Active Variant"} </label>
+                </div>
+            </div>
+            {variant_inputs}
+            <div class={INPUT_FIELD_WRAPPER_CLASS}>
+                <div class="flex items-center justify-between px-1 py-1">
+                    <div class={SUBSECTION_LABEL_CLASS}>{"Section Order"}</div>
+                    {reset_order}
+                </div>
+                {order_rows}
+            </div>
+        </>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::bullets::bullets_from_lines;
+    use crate::pages::state::default_seed_state;
+
+    fn tags(raw: &str) -> Vec<String> {
+        parse_tags(raw)
+    }
+
+    #[test]
+    fn untagged_items_match_every_variant() {
+        assert!(tags_match(&[], &tags("backend")));
+        assert!(tags_match(&tags("data"), &[]));
+        assert!(tags_match(&tags("Backend, data"), &tags("backend")));
+        assert!(!tags_match(&tags("management"), &tags("backend, data")));
+    }
+
+    #[test]
+    fn split_line_tags_strips_trailing_block() {
+        let (text, line_tags) = split_line_tags("Shipped **things** {backend, data}");
+        assert_eq!(text, "Shipped **things**");
+        assert_eq!(line_tags, tags("backend, data"));
+
+        let (text, line_tags) = split_line_tags("No tags here");
+        assert_eq!(text, "No tags here");
+        assert!(line_tags.is_empty());
+    }
+
+    #[test]
+    fn apply_variant_filters_items_and_bullets() {
+        let mut state = default_seed_state();
+        state.projects[0].tags = tags("management");
+        state.experiences[0].bullets =
+            bullets_from_lines("Led a team {management}\nBuilt a pipeline {backend}");
+        let variant = Variant {
+            name: "Backend".to_string(),
+            tags: tags("backend"),
+            ..Variant::default()
+        };
+
+        let filtered = apply_variant(&state, Some(&variant));
+        assert_eq!(filtered.projects.len(), state.projects.len() - 1);
+        assert_eq!(filtered.experiences[0].bullets.len(), 1);
+        assert_eq!(filtered.experiences[0].bullets[0].text, "Built a pipeline");
+        assert_eq!(apply_variant(&state, None), state);
+    }
+
+    #[test]
+    fn section_layout_uses_variant_overrides() {
+        let state = default_seed_state();
+        let variant = Variant {
+            section_order: vec![Section::Education],
+            headings: BTreeMap::from([(Section::Education, "Academics".to_string())]),
+            ..Variant::default()
+        };
+
+        let layout = section_layout(&state, Some(&variant));
+        assert_eq!(layout[0], (Section::Education, "Academics".to_string()));
+        assert_eq!(layout.len(), Section::all().len());
+    }
+}